@@ -0,0 +1,103 @@
+use std::time::{Duration, Instant};
+
+use aoc_common::{Error, Result};
+
+use crate::days::Day;
+
+/// Median time taken by each stage of solving a day.
+#[derive(Copy, Clone, Debug)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Option<Duration>,
+    pub part2: Option<Duration>,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.unwrap_or_default() + self.part2.unwrap_or_default()
+    }
+}
+
+fn median<T>(iterations: usize, mut f: impl FnMut() -> Result<T>) -> Result<Duration> {
+    let mut samples = Vec::with_capacity(iterations);
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        f()?;
+        samples.push(start.elapsed());
+    }
+
+    samples.sort_unstable();
+
+    Ok(samples[samples.len() / 2])
+}
+
+/// Times a part, treating unsolved parts as having no timing rather than failing.
+fn time_part(iterations: usize, f: impl FnMut() -> Result<String>) -> Result<Option<Duration>> {
+    match median(iterations, f) {
+        Ok(duration) => Ok(Some(duration)),
+        Err(Error::Unsolved) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn bench(day: &Day, input: &str, iterations: usize) -> Result<Timings> {
+    let parse = median(iterations, || (day.parse)(input))?;
+    let puzzle = (day.parse)(input)?;
+
+    Ok(Timings {
+        parse,
+        part1: time_part(iterations, || puzzle.part1())?,
+        part2: time_part(iterations, || puzzle.part2())?,
+    })
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    match nanos {
+        0..=999 => format!("{}ns", nanos),
+        1_000..=999_999 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+fn cell(duration: Option<Duration>) -> String {
+    duration.map_or_else(|| String::from("-"), format_duration)
+}
+
+/// Prints one row of the summary table, or the reason the day could not be timed.
+pub fn print_row(day: u8, timings: &Result<Timings, String>) {
+    match timings {
+        Ok(t) => println!(
+            "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+            day,
+            format_duration(t.parse),
+            cell(t.part1),
+            cell(t.part2),
+            format_duration(t.total())
+        ),
+        Err(reason) => println!("{:>3}  {}", day, reason),
+    }
+}
+
+pub fn print_header() {
+    println!(
+        "{:>3}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Day", "Parse", "Part 1", "Part 2", "Total"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn durations_use_sensible_units() {
+        assert_eq!(format_duration(Duration::from_nanos(850)), "850ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_340)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(4_560)), "4.56ms");
+        assert_eq!(format_duration(Duration::from_millis(1_230)), "1.23s");
+    }
+}
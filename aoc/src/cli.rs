@@ -0,0 +1,288 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use aoc_common::render::ImageFormat;
+use aoc_common::Source;
+
+use crate::output::Format;
+
+const USAGE: &str = "\
+Usage: aoc run <day> [--part <1|2>] [--input <path|->] [--format <text|json>]
+       aoc run --all [--part <1|2>] [--format <text|json>]
+       aoc bench [<day>] [--iterations <n>] [--input <path|->]
+       aoc check [<day>] [--record] [--answers <path>]
+       aoc fetch [<day>]
+       aoc new <day>
+       aoc render <day> [--input <path|->] [--output <dir>] [--format <ppm|png>]
+
+Inputs are read from <day>/input.txt or the cache in $AOC_CACHE_DIR (default `inputs`),
+downloading them when $AOC_SESSION is set.";
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(format!("Invalid part '{}', expected 1 or 2", value)),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> u8 {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum Command {
+    Run {
+        /// Every day is run in parallel when this is `None`.
+        day: Option<u8>,
+        part: Option<Part>,
+        input: Option<Source>,
+        format: Format,
+    },
+    Bench {
+        day: Option<u8>,
+        iterations: usize,
+        input: Option<Source>,
+    },
+    Check {
+        day: Option<u8>,
+        record: bool,
+        answers: PathBuf,
+    },
+    Fetch {
+        day: Option<u8>,
+    },
+    New {
+        day: u8,
+    },
+    Render {
+        day: u8,
+        input: Option<Source>,
+        output: PathBuf,
+        format: ImageFormat,
+    },
+}
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid day '{}', expected a number", value))
+}
+
+/// Takes an optional leading day, leaving any options behind it.
+fn parse_optional_day(
+    args: &mut std::iter::Peekable<impl Iterator<Item = String>>,
+) -> Result<Option<u8>, String> {
+    match args.next_if(|arg| !arg.starts_with("--")) {
+        Some(day) => Ok(Some(parse_day(&day)?)),
+        None => Ok(None),
+    }
+}
+
+/// Walks `--flag value` pairs, handing each to `apply`.
+fn parse_options(
+    mut args: impl Iterator<Item = String>,
+    mut apply: impl FnMut(&str, String) -> Result<(), String>,
+) -> Result<(), String> {
+    while let Some(arg) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| format!("Missing value for '{}'\n{}", arg, USAGE))?;
+
+        apply(&arg, value)?;
+    }
+
+    Ok(())
+}
+
+fn parse_image_format(value: &str) -> Result<ImageFormat, String> {
+    match value {
+        "ppm" => Ok(ImageFormat::Ppm),
+        "png" => Ok(ImageFormat::Png),
+        _ => Err(format!(
+            "Invalid image format '{}', expected ppm or png",
+            value
+        )),
+    }
+}
+
+fn unknown_argument(arg: &str) -> Result<(), String> {
+    Err(format!("Unknown argument '{}'\n{}", arg, USAGE))
+}
+
+impl Command {
+    pub fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        match args.next().as_deref() {
+            Some("run") => Self::parse_run(args),
+            Some("bench") => Self::parse_bench(args),
+            Some("check") => Self::parse_check(args),
+            Some("fetch") => Self::parse_fetch(args),
+            Some("new") => Self::parse_new(args),
+            Some("render") => Self::parse_render(args),
+            Some(other) => Err(format!("Unknown command '{}'\n{}", other, USAGE)),
+            None => Err(USAGE.to_string()),
+        }
+    }
+
+    fn parse_run(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let day = match args.next().ok_or_else(|| USAGE.to_string())?.as_str() {
+            "--all" => None,
+            day => Some(parse_day(day)?),
+        };
+
+        let mut part = None;
+        let mut input = None;
+        let mut format = Format::default();
+
+        parse_options(args, |arg, value| {
+            match arg {
+                "--part" => part = Some(Part::parse(&value)?),
+                "--input" => input = Some(Source::from_arg(&value)),
+                "--format" => format = Format::parse(&value)?,
+                _ => return unknown_argument(arg),
+            }
+
+            Ok(())
+        })?;
+
+        if day.is_none() && input.is_some() {
+            return Err(String::from(
+                "--input can only be used when running a single day",
+            ));
+        }
+
+        Ok(Self::Run {
+            day,
+            part,
+            input,
+            format,
+        })
+    }
+
+    fn parse_bench(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.peekable();
+
+        let day = parse_optional_day(&mut args)?;
+
+        let mut iterations = 10;
+        let mut input = None;
+
+        parse_options(args, |arg, value| {
+            match arg {
+                "--iterations" => {
+                    iterations = value
+                        .parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or_else(|| format!("Invalid iteration count '{}'", value))?
+                }
+                "--input" => input = Some(Source::from_arg(&value)),
+                _ => return unknown_argument(arg),
+            }
+
+            Ok(())
+        })?;
+
+        if day.is_none() && input.is_some() {
+            return Err(String::from(
+                "--input can only be used when benchmarking a single day",
+            ));
+        }
+
+        Ok(Self::Bench {
+            day,
+            iterations,
+            input,
+        })
+    }
+
+    fn parse_check(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.peekable();
+        let day = parse_optional_day(&mut args)?;
+
+        // `--record` is the only flag without a value, so pull it out first
+        let (flags, args): (Vec<_>, Vec<_>) = args.partition(|arg| arg == "--record");
+
+        let mut answers = PathBuf::from("answers.toml");
+
+        parse_options(args.into_iter(), |arg, value| {
+            match arg {
+                "--answers" => answers = PathBuf::from(value),
+                _ => return unknown_argument(arg),
+            }
+
+            Ok(())
+        })?;
+
+        Ok(Self::Check {
+            day,
+            record: !flags.is_empty(),
+            answers,
+        })
+    }
+
+    fn parse_fetch(args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.peekable();
+        let day = parse_optional_day(&mut args)?;
+
+        parse_options(args, |arg, _| unknown_argument(arg))?;
+
+        Ok(Self::Fetch { day })
+    }
+
+    fn parse_new(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let day = parse_day(&args.next().ok_or_else(|| USAGE.to_string())?)?;
+
+        parse_options(args, |arg, _| unknown_argument(arg))?;
+
+        Ok(Self::New { day })
+    }
+
+    fn parse_render(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let day = parse_day(&args.next().ok_or_else(|| USAGE.to_string())?)?;
+
+        let mut input = None;
+        let mut output = PathBuf::from("frames").join(format!("day{}", day));
+        let mut format = ImageFormat::Png;
+
+        parse_options(args, |arg, value| {
+            match arg {
+                "--input" => input = Some(Source::from_arg(&value)),
+                "--output" => output = PathBuf::from(value),
+                "--format" => format = parse_image_format(&value)?,
+                _ => return unknown_argument(arg),
+            }
+
+            Ok(())
+        })?;
+
+        Ok(Self::Render {
+            day,
+            input,
+            output,
+            format,
+        })
+    }
+}
@@ -0,0 +1,73 @@
+use aoc_common::render::{Image, Render};
+use aoc_common::{Result, Solution};
+
+/// Object safe view of a parsed [`Solution`], so days can be stored side by side.
+pub trait Puzzle: Send + Sync {
+    fn part1(&self) -> Result<String>;
+
+    fn part2(&self) -> Result<String>;
+}
+
+impl<S: Solution + Send + Sync> Puzzle for S {
+    fn part1(&self) -> Result<String> {
+        Ok(Solution::part1(self)?.to_string())
+    }
+
+    fn part2(&self) -> Result<String> {
+        Ok(Solution::part2(self)?.to_string())
+    }
+}
+
+/// Parses a day's input and draws it as a series of frames.
+pub type Renderer = fn(&str) -> Result<Vec<Image>>;
+
+#[derive(Copy, Clone)]
+pub struct Day {
+    pub number: u8,
+    pub parse: fn(&str) -> Result<Box<dyn Puzzle>>,
+    pub render: Option<Renderer>,
+}
+
+fn parse<S: Solution + Send + Sync + 'static>(input: &str) -> Result<Box<dyn Puzzle>> {
+    Ok(Box::new(S::from_input(input)?))
+}
+
+fn render<S: Solution + Render>(input: &str) -> Result<Vec<Image>> {
+    Ok(S::from_input(input)?.frames())
+}
+
+impl Day {
+    const fn new<S: Solution + Send + Sync + 'static>(number: u8) -> Self {
+        Self {
+            number,
+            parse: parse::<S>,
+            render: None,
+        }
+    }
+
+    /// A day whose state can also be drawn with `aoc render`.
+    const fn renderable<S: Solution + Render + Send + Sync + 'static>(number: u8) -> Self {
+        Self {
+            render: Some(render::<S>),
+            ..Self::new::<S>(number)
+        }
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::SonarSweep>(1),
+    Day::new::<day2::Course>(2),
+    Day::new::<day3::Diagnostic>(3),
+    Day::renderable::<day4::BingoFile>(4),
+    Day::renderable::<day5::Input>(5),
+    Day::new::<day6::State>(6),
+    Day::new::<day7::Crabs>(7),
+    Day::new::<day8::Input>(8),
+    Day::renderable::<day9::DepthMap>(9),
+    Day::new::<day10::NavigationSubsystem>(10),
+    Day::renderable::<day13::Input>(13),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,177 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::input::{self, YEAR};
+use aoc_common::Source;
+
+const USER_AGENT: &str = "github.com/alexander-jackson/aoc21";
+
+/// Finds puzzle inputs, downloading them into a local cache when they aren't present yet.
+///
+/// Configured through the environment:
+/// - `AOC_SESSION`: the session cookie used to download inputs
+/// - `AOC_CACHE_DIR`: where inputs are cached, defaulting to `inputs`
+/// - `AOC_BASE_URL`: the site to download from, which tests point at a local stand-in
+#[derive(Clone, Debug)]
+pub struct InputManager {
+    cache_dir: PathBuf,
+    base_url: String,
+    session: Option<String>,
+}
+
+impl InputManager {
+    pub fn new(cache_dir: PathBuf, base_url: String, session: Option<String>) -> Self {
+        Self {
+            cache_dir,
+            base_url,
+            session,
+        }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(
+            input::cache_dir(),
+            std::env::var("AOC_BASE_URL")
+                .unwrap_or_else(|_| String::from("https://adventofcode.com")),
+            std::env::var("AOC_SESSION").ok().filter(|s| !s.is_empty()),
+        )
+    }
+
+    pub fn cached_path(&self, day: u8) -> PathBuf {
+        input::cached_path(&self.cache_dir, day)
+    }
+
+    /// Works out where a day's input lives, downloading it first if possible.
+    ///
+    /// An `input.txt` placed in the day's crate takes priority over the cache.
+    pub fn resolve(&self, day: u8) -> Result<Source, Box<dyn std::error::Error>> {
+        if let Some(path) = input::find_local(Path::new(""), &self.cache_dir, day) {
+            return Ok(Source::File(path));
+        }
+
+        if self.session.is_some() {
+            self.download(day)?;
+        }
+
+        Ok(Source::File(self.cached_path(day)))
+    }
+
+    /// Downloads a day's input into the cache, returning where it was written.
+    pub fn download(&self, day: u8) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let session = self
+            .session
+            .as_ref()
+            .ok_or("AOC_SESSION must be set to download puzzle inputs")?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, YEAR, day);
+
+        let input = ureq::get(&url)
+            .header("Cookie", &format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .map_err(|e| format!("Failed to download {}: {}", url, e))?
+            .body_mut()
+            .read_to_string()?;
+
+        let path = self.cached_path(day);
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        std::fs::write(&path, input)?;
+
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    use super::*;
+
+    /// Serves a single request like the puzzle site would, returning the request line and headers.
+    fn stand_in(status: &'static str, body: &'static str) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+
+            let request: Vec<_> = BufReader::new(&stream)
+                .lines()
+                .map(Result::unwrap)
+                .take_while(|line| !line.is_empty())
+                .collect();
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            request
+        });
+
+        (url, handle)
+    }
+
+    fn manager(base_url: String, session: Option<&str>) -> InputManager {
+        let cache_dir = std::env::temp_dir().join(format!(
+            "aoc-inputs-{}-{}",
+            std::process::id(),
+            base_url.rsplit(':').next().unwrap()
+        ));
+
+        InputManager::new(cache_dir, base_url, session.map(String::from))
+    }
+
+    #[test]
+    fn inputs_are_downloaded_and_cached() {
+        let (url, server) = stand_in("200 OK", "3,4,3,1,2\n");
+        let manager = manager(url, Some("secret"));
+
+        let source = manager.resolve(6).unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(request[0], "GET /2021/day/6/input HTTP/1.1");
+        assert!(request
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case("cookie: session=secret")));
+        assert_eq!(source, Source::File(manager.cached_path(6)));
+        assert_eq!(source.read().unwrap(), "3,4,3,1,2\n");
+
+        // The stand-in only answers once, so this must come from the cache
+        assert_eq!(manager.resolve(6).unwrap().read().unwrap(), "3,4,3,1,2\n");
+
+        std::fs::remove_dir_all(&manager.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn failed_downloads_are_reported() {
+        let (url, server) = stand_in("400 Bad Request", "Puzzle inputs differ by user.");
+        let manager = manager(url, Some("expired"));
+
+        let error = manager.download(7).unwrap_err();
+        server.join().unwrap();
+
+        assert!(error.to_string().starts_with("Failed to download"));
+        assert!(!manager.cached_path(7).exists());
+    }
+
+    #[test]
+    fn downloads_need_a_session() {
+        let manager = manager(String::from("http://127.0.0.1:1"), None);
+
+        assert!(manager.download(8).is_err());
+        assert_eq!(
+            manager.resolve(8).unwrap(),
+            Source::File(manager.cached_path(8))
+        );
+    }
+}
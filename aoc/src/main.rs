@@ -0,0 +1,369 @@
+use std::path::PathBuf;
+use std::time::Instant;
+
+mod bench;
+mod cli;
+mod days;
+mod inputs;
+mod output;
+mod parallel;
+mod scaffold;
+
+use aoc_common::answers::{AnswerStore, Verdict};
+use aoc_common::render::{Frames, ImageFormat};
+use aoc_common::{Error, Source};
+
+use cli::{Command, Part};
+use inputs::InputManager;
+use output::Format;
+use parallel::Outcome;
+
+/// Picks out a single day, or every day when none is given.
+fn select(day: Option<u8>) -> Result<Vec<&'static days::Day>, String> {
+    match day {
+        Some(number) => {
+            Ok(vec![days::find(number).ok_or_else(|| {
+                format!("Day {} has not been solved", number)
+            })?])
+        }
+        None => Ok(days::DAYS.iter().collect()),
+    }
+}
+
+fn run(
+    day: u8,
+    part: Option<Part>,
+    input: Option<Source>,
+    format: Format,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let day = days::find(day).ok_or_else(|| format!("Day {} has not been solved", day))?;
+
+    let source = match input {
+        Some(source) => source,
+        None => InputManager::from_env().resolve(day.number)?,
+    };
+
+    let input = source.read()?;
+
+    let puzzle = (day.parse)(&input)?;
+
+    for current in [Part::One, Part::Two] {
+        if part.is_some_and(|part| part != current) {
+            continue;
+        }
+
+        let start = Instant::now();
+
+        let result = match current {
+            Part::One => puzzle.part1(),
+            Part::Two => puzzle.part2(),
+        };
+
+        output::print_result(format, day.number, current, result, start.elapsed())?;
+    }
+
+    Ok(())
+}
+
+fn run_all(part: Option<Part>, format: Format) -> Result<(), Box<dyn std::error::Error>> {
+    // Keep stdout to just the records when it is being read by another program
+    let note = |message: String| match format {
+        Format::Text => println!("{}", message),
+        Format::Json => eprintln!("{}", message),
+    };
+
+    let start = Instant::now();
+    let reports = parallel::run_all(&select(None)?, &InputManager::from_env(), part);
+    let wall_time = start.elapsed();
+
+    let days = reports
+        .iter()
+        .filter(|r| matches!(r.outcome, Outcome::Solved { .. }))
+        .count();
+    let cpu_time: std::time::Duration = reports.iter().map(|r| r.cpu_time()).sum();
+    let mut failures = 0;
+
+    for report in reports {
+        match report.outcome {
+            Outcome::Solved { parts, .. } => {
+                if format == Format::Text {
+                    println!("Day {}", report.day);
+                }
+
+                for result in parts {
+                    let printed = output::print_result(
+                        format,
+                        report.day,
+                        result.part,
+                        result.result,
+                        result.duration,
+                    );
+
+                    if let Err(e) = printed {
+                        failures += 1;
+                        note(format!("Part {}: failed, {}", result.part, e));
+                    }
+                }
+
+                if format == Format::Text {
+                    println!();
+                }
+            }
+            Outcome::Skipped(reason) => note(format!("Day {}: skipped, {}\n", report.day, reason)),
+            Outcome::Failed(reason) => {
+                failures += 1;
+                note(format!("Day {}: failed, {}\n", report.day, reason));
+            }
+        }
+    }
+
+    note(format!(
+        "Ran {} days in {} wall time ({} summed across threads)",
+        days,
+        bench::format_duration(wall_time),
+        bench::format_duration(cpu_time),
+    ));
+
+    match failures {
+        0 => Ok(()),
+        n => Err(format!("{} puzzles failed", n).into()),
+    }
+}
+
+fn bench(
+    day: Option<u8>,
+    iterations: usize,
+    input: Option<Source>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let inputs = InputManager::from_env();
+
+    bench::print_header();
+
+    for day in select(day)? {
+        let source = match &input {
+            Some(source) => source.clone(),
+            None => inputs.resolve(day.number)?,
+        };
+
+        let timings = match source.read() {
+            Ok(input) => bench::bench(day, &input, iterations).map_err(|e| e.to_string()),
+            Err(e) if e.is_missing() => Err(format!("no input found at {}", source)),
+            Err(e) => Err(e.to_string()),
+        };
+
+        bench::print_row(day.number, &timings);
+    }
+
+    Ok(())
+}
+
+fn check(
+    day: Option<u8>,
+    record: bool,
+    answers: PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let inputs = InputManager::from_env();
+    let mut store = AnswerStore::load(&answers)?;
+    let (mut passed, mut failed, mut unknown) = (0, 0, 0);
+    // Answers that couldn't be computed at all, which are also counted as failures
+    let mut errors = 0;
+
+    for day in select(day)? {
+        let source = inputs.resolve(day.number)?;
+
+        let input = match source.read() {
+            Ok(input) => input,
+            Err(e) if e.is_missing() => {
+                println!("Day {}: skipped, no input found at {}", day.number, source);
+                continue;
+            }
+            Err(e) => {
+                println!("Day {}: FAIL, could not read {}: {}", day.number, source, e);
+                (failed, errors) = (failed + 2, errors + 2);
+                continue;
+            }
+        };
+
+        let puzzle = match (day.parse)(&input) {
+            Ok(puzzle) => puzzle,
+            Err(e) => {
+                println!("Day {}: FAIL, could not parse the input: {}", day.number, e);
+                (failed, errors) = (failed + 2, errors + 2);
+                continue;
+            }
+        };
+
+        for (part, result) in [(Part::One, puzzle.part1()), (Part::Two, puzzle.part2())] {
+            let label = format!("Day {} part {}", day.number, part);
+
+            let answer = match result {
+                Ok(answer) => answer,
+                Err(Error::Unsolved) => continue,
+                Err(e) => {
+                    println!("{}: FAIL, {}", label, e);
+                    (failed, errors) = (failed + 1, errors + 1);
+                    continue;
+                }
+            };
+
+            // Pictures don't fit on a single line, so only show the verdict for them
+            let shown = match answer.contains('\n') {
+                true => String::new(),
+                false => format!(" ({})", answer),
+            };
+
+            match store.check(day.number, part.into(), &answer) {
+                Verdict::Pass => {
+                    passed += 1;
+                    println!("{}: pass{}", label, shown);
+                }
+                Verdict::Fail { expected } => {
+                    failed += 1;
+
+                    match expected.contains('\n') {
+                        true => println!("{}: FAIL, expected:\n{}", label, expected),
+                        false => println!("{}: FAIL{}, expected {}", label, shown, expected),
+                    }
+                }
+                Verdict::Unknown => {
+                    unknown += 1;
+                    println!("{}: unknown{}", label, shown);
+                }
+            }
+
+            if record {
+                store.record(day.number, part.into(), answer);
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} unknown",
+        passed, failed, unknown
+    );
+
+    if record {
+        store.save()?;
+        println!("Recorded answers to {}", store.path().display());
+    }
+
+    if errors > 0 {
+        return Err(format!("{} answers could not be computed", errors).into());
+    } else if failed > 0 && !record {
+        return Err(format!("{} answers did not match {}", failed, answers.display()).into());
+    }
+
+    Ok(())
+}
+
+fn fetch(day: Option<u8>) -> Result<(), Box<dyn std::error::Error>> {
+    let inputs = InputManager::from_env();
+
+    for day in select(day)? {
+        let path = inputs.cached_path(day.number);
+
+        if path.exists() {
+            println!("Day {}: already cached at {}", day.number, path.display());
+        } else {
+            let path = inputs.download(day.number)?;
+            println!("Day {}: downloaded to {}", day.number, path.display());
+        }
+    }
+
+    Ok(())
+}
+
+fn new(day: u8) -> Result<(), Box<dyn std::error::Error>> {
+    let root = std::env::current_dir()?;
+
+    let is_workspace = std::fs::read_to_string(root.join("Cargo.toml"))
+        .map(|manifest| manifest.contains("[workspace]"))
+        .unwrap_or_default();
+
+    if !is_workspace {
+        return Err("`aoc new` must be run from the root of the workspace".into());
+    }
+
+    for path in scaffold::generate(&root, day)? {
+        println!(
+            "Wrote {}",
+            path.strip_prefix(&root).unwrap_or(&path).display()
+        );
+    }
+
+    println!(
+        "Run `cargo test -p day{}` once the example input is in place",
+        day
+    );
+
+    Ok(())
+}
+
+fn render(
+    day: u8,
+    input: Option<Source>,
+    output: PathBuf,
+    format: ImageFormat,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let day = days::find(day).ok_or_else(|| format!("Day {} has not been solved", day))?;
+    let render = day
+        .render
+        .ok_or_else(|| format!("Day {} has nothing to render", day.number))?;
+
+    let source = match input {
+        Some(source) => source,
+        None => InputManager::from_env().resolve(day.number)?,
+    };
+
+    let images = render(&source.read()?)?;
+    let mut frames = Frames::new(&output, "frame", format)?;
+
+    for image in &images {
+        frames.push(image)?;
+    }
+
+    println!("Wrote {} frames to {}", images.len(), output.display());
+
+    Ok(())
+}
+
+fn main() {
+    let result = match Command::parse(std::env::args().skip(1)) {
+        Ok(Command::Run {
+            day,
+            part,
+            input,
+            format,
+        }) => match day {
+            Some(day) => run(day, part, input, format),
+            None => run_all(part, format),
+        },
+        Ok(Command::Bench {
+            day,
+            iterations,
+            input,
+        }) => bench(day, iterations, input),
+        Ok(Command::Check {
+            day,
+            record,
+            answers,
+        }) => check(day, record, answers),
+        Ok(Command::Fetch { day }) => fetch(day),
+        Ok(Command::New { day }) => new(day),
+        Ok(Command::Render {
+            day,
+            input,
+            output,
+            format,
+        }) => render(day, input, output, format),
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
+}
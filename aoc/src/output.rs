@@ -0,0 +1,99 @@
+use std::time::Duration;
+
+use serde::Serialize;
+
+use aoc_common::Error;
+
+use crate::cli::Part;
+
+/// How answers are written to stdout.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Format {
+    #[default]
+    Text,
+    /// One JSON object per line, for scripts and dashboards.
+    Json,
+}
+
+impl Format {
+    pub fn parse(value: &str) -> Result<Self, String> {
+        match value {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            _ => Err(format!("Invalid format '{}', expected text or json", value)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a str,
+    duration_ms: f64,
+}
+
+fn print_text(part: Part, answer: &str) {
+    // Some answers (such as day 13) are pictures, so start them on their own line
+    if answer.contains('\n') {
+        println!("Part {}:\n{}", part, answer);
+    } else {
+        println!("Part {}: {}", part, answer);
+    }
+}
+
+/// Prints the answer to one part, or fails if it couldn't be solved. Parts that haven't been
+/// solved yet are mentioned in text output and left out of JSON.
+pub fn print_result(
+    format: Format,
+    day: u8,
+    part: Part,
+    result: aoc_common::Result<String>,
+    duration: Duration,
+) -> aoc_common::Result<()> {
+    let answer = match result {
+        Ok(answer) => answer,
+        Err(Error::Unsolved) if format == Format::Text => Error::Unsolved.to_string(),
+        Err(Error::Unsolved) => return Ok(()),
+        Err(e) => return Err(e),
+    };
+
+    match format {
+        Format::Text => print_text(part, &answer),
+        Format::Json => {
+            let record = Record {
+                day,
+                part: part.into(),
+                answer: &answer,
+                duration_ms: duration.as_secs_f64() * 1e3,
+            };
+
+            println!(
+                "{}",
+                serde_json::to_string(&record).expect("records are always serializable")
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_are_serialized() {
+        let record = Record {
+            day: 13,
+            part: 2,
+            answer: "# #\n. #\n",
+            duration_ms: 1.5,
+        };
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            r##"{"day":13,"part":2,"answer":"# #\n. #\n","duration_ms":1.5}"##
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use std::time::{Duration, Instant};
+
+use crate::cli::Part;
+use crate::days::{Day, Puzzle};
+use crate::inputs::InputManager;
+
+pub struct PartResult {
+    pub part: Part,
+    pub result: aoc_common::Result<String>,
+    pub duration: Duration,
+}
+
+pub enum Outcome {
+    Solved {
+        parse: Duration,
+        parts: Vec<PartResult>,
+    },
+    Skipped(String),
+    Failed(String),
+}
+
+pub struct DayReport {
+    pub day: u8,
+    pub outcome: Outcome,
+}
+
+impl DayReport {
+    /// Time spent parsing and solving, regardless of which threads did the work.
+    pub fn cpu_time(&self) -> Duration {
+        match &self.outcome {
+            Outcome::Solved { parse, parts } => *parse + parts.iter().map(|p| p.duration).sum(),
+            _ => Duration::ZERO,
+        }
+    }
+}
+
+fn solve(puzzle: &dyn Puzzle, part: Part) -> PartResult {
+    let start = Instant::now();
+
+    let result = match part {
+        Part::One => puzzle.part1(),
+        Part::Two => puzzle.part2(),
+    };
+
+    PartResult {
+        part,
+        result,
+        duration: start.elapsed(),
+    }
+}
+
+fn run_day(day: &Day, inputs: &InputManager, part: Option<Part>) -> Outcome {
+    let source = match inputs.resolve(day.number) {
+        Ok(source) => source,
+        Err(e) => return Outcome::Failed(e.to_string()),
+    };
+
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(e) if e.is_missing() => {
+            return Outcome::Skipped(format!("no input found at {}", source))
+        }
+        Err(e) => return Outcome::Failed(e.to_string()),
+    };
+
+    let start = Instant::now();
+
+    let puzzle = match (day.parse)(&input) {
+        Ok(puzzle) => puzzle,
+        Err(e) => return Outcome::Failed(e.to_string()),
+    };
+
+    let parse = start.elapsed();
+
+    // The parts only share the parsed input, so they can run side by side as well
+    let parts = match part {
+        Some(part) => vec![solve(&*puzzle, part)],
+        None => {
+            let (first, second) =
+                rayon::join(|| solve(&*puzzle, Part::One), || solve(&*puzzle, Part::Two));
+
+            vec![first, second]
+        }
+    };
+
+    Outcome::Solved { parse, parts }
+}
+
+/// Runs every given day concurrently, returning the reports in the same order as `days`.
+pub fn run_all(days: &[&Day], inputs: &InputManager, part: Option<Part>) -> Vec<DayReport> {
+    use rayon::prelude::*;
+
+    days.par_iter()
+        .map(|day| DayReport {
+            day: day.number,
+            outcome: run_day(day, inputs, part),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use aoc_common::Error;
+
+    use super::*;
+
+    /// Answers part 1 with its input and leaves part 2 unsolved.
+    struct Echo(String);
+
+    impl Puzzle for Echo {
+        fn part1(&self) -> aoc_common::Result<String> {
+            Ok(self.0.clone())
+        }
+
+        fn part2(&self) -> aoc_common::Result<String> {
+            Err(Error::Unsolved)
+        }
+    }
+
+    fn echo(input: &str) -> aoc_common::Result<Box<dyn Puzzle>> {
+        match input.trim() {
+            "" => Err(Error::EmptyInput),
+            input => Ok(Box::new(Echo(input.to_string()))),
+        }
+    }
+
+    fn day(number: u8) -> Day {
+        Day {
+            number,
+            parse: echo,
+            render: None,
+        }
+    }
+
+    /// An input manager reading from a fresh cache holding `inputs`, which never downloads.
+    fn manager(name: &str, inputs: &[(u8, &str)]) -> (InputManager, PathBuf) {
+        let cache_dir =
+            std::env::temp_dir().join(format!("aoc-parallel-{}-{}", std::process::id(), name));
+        let manager =
+            InputManager::new(cache_dir.clone(), String::from("http://127.0.0.1:1"), None);
+
+        for (day, input) in inputs {
+            let path = manager.cached_path(*day);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, input).unwrap();
+        }
+
+        (manager, cache_dir)
+    }
+
+    fn answers(outcome: &Outcome) -> Vec<(u8, String)> {
+        match outcome {
+            Outcome::Solved { parts, .. } => parts
+                .iter()
+                .map(|p| (u8::from(p.part), format!("{:?}", p.result)))
+                .collect(),
+            _ => panic!("the day wasn't solved"),
+        }
+    }
+
+    #[test]
+    fn reports_come_back_in_day_order() {
+        let numbers = [34, 31, 33, 32, 30];
+        let inputs: Vec<_> = numbers.iter().map(|n| (*n, "answer")).collect();
+        let (manager, cache_dir) = manager("order", &inputs);
+
+        let days: Vec<_> = numbers.iter().map(|n| day(*n)).collect();
+        let days: Vec<_> = days.iter().collect();
+        let reports = run_all(&days, &manager, None);
+
+        assert_eq!(reports.iter().map(|r| r.day).collect::<Vec<_>>(), numbers);
+
+        std::fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn skipped_and_failed_days_dont_stop_the_others() {
+        let (manager, cache_dir) = manager("outcomes", &[(41, "\n"), (42, "fine")]);
+
+        let days = [day(40), day(41), day(42)];
+        let reports = run_all(&days.iter().collect::<Vec<_>>(), &manager, None);
+
+        assert!(matches!(reports[0].outcome, Outcome::Skipped(_)));
+        assert!(
+            matches!(&reports[1].outcome, Outcome::Failed(e) if e == "the puzzle input is empty")
+        );
+        assert_eq!(
+            answers(&reports[2].outcome),
+            [
+                (1, String::from("Ok(\"fine\")")),
+                (2, String::from("Err(Unsolved)"))
+            ]
+        );
+
+        let only_second = run_all(&[&days[2]], &manager, Some(Part::Two));
+
+        assert_eq!(
+            answers(&only_second[0].outcome),
+            [(2, String::from("Err(Unsolved)"))]
+        );
+
+        std::fs::remove_dir_all(cache_dir).unwrap();
+    }
+
+    #[test]
+    fn cpu_time_adds_up_parsing_and_parts() {
+        let part = |part, millis| PartResult {
+            part,
+            result: Ok(String::new()),
+            duration: Duration::from_millis(millis),
+        };
+
+        let solved = DayReport {
+            day: 1,
+            outcome: Outcome::Solved {
+                parse: Duration::from_millis(5),
+                parts: vec![part(Part::One, 20), part(Part::Two, 300)],
+            },
+        };
+        let skipped = DayReport {
+            day: 2,
+            outcome: Outcome::Skipped(String::from("no input")),
+        };
+
+        assert_eq!(solved.cpu_time(), Duration::from_millis(325));
+        assert_eq!(skipped.cpu_time(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,187 @@
+use std::path::{Path, PathBuf};
+
+const CARGO_TEMPLATE: &str = include_str!("templates/Cargo.toml.template");
+const LIB_TEMPLATE: &str = include_str!("templates/lib.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("templates/main.rs.template");
+
+const EXAMPLE_PLACEHOLDER: &str = "Replace this with the example input from the puzzle\n";
+
+fn render(template: &str, day: u8) -> String {
+    template.replace("{{day}}", &day.to_string())
+}
+
+/// Finds the day number in a line such as `    Day::new::<day5::Input>(5),` or `day5 = { .. }`.
+fn day_of(line: &str) -> Option<u8> {
+    let start = line.find("day")? + "day".len();
+    let digits: String = line[start..]
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+
+    digits.parse().ok()
+}
+
+/// Inserts `entry` amongst the lines that name a day, keeping them in day order.
+fn insert_in_order(
+    content: &str,
+    day: u8,
+    entry: String,
+    is_entry: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let mut lines: Vec<_> = content.lines().map(String::from).collect();
+
+    let entries: Vec<_> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| is_entry(line))
+        .map(|(i, line)| (i, day_of(line)))
+        .collect();
+
+    let last = entries.last()?.0;
+    let position = entries
+        .iter()
+        .find(|(_, existing)| existing.is_some_and(|existing| existing > day))
+        .map_or(last + 1, |(i, _)| *i);
+
+    lines.insert(position, entry);
+
+    Some(lines.join("\n") + "\n")
+}
+
+/// Adds a day to the runner's registry of solutions.
+pub fn register_day(days: &str, day: u8) -> Option<String> {
+    insert_in_order(
+        days,
+        day,
+        format!("    Day::new::<day{}::Input>({}),", day, day),
+        |line| line.trim_start().starts_with("Day::") && line.contains("::<day"),
+    )
+}
+
+/// Adds a day's crate to the runner's dependencies.
+pub fn add_dependency(manifest: &str, day: u8) -> Option<String> {
+    insert_in_order(
+        manifest,
+        day,
+        format!("day{} = {{ path = \"../day{}\" }}", day, day),
+        |line| line.starts_with("day") && line.contains("path = \"../day"),
+    )
+}
+
+/// Generates a new day's crate in `root` and wires it into the runner, returning the files written.
+pub fn generate(root: &Path, day: u8) -> Result<Vec<PathBuf>, Box<dyn std::error::Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("Day {} is not part of the calendar", day).into());
+    }
+
+    let crate_dir = root.join(format!("day{}", day));
+
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()).into());
+    }
+
+    let days_path = root.join("aoc/src/days.rs");
+    let manifest_path = root.join("aoc/Cargo.toml");
+
+    let days = std::fs::read_to_string(&days_path)
+        .map_err(|e| format!("Failed to read {}: {}", days_path.display(), e))?;
+    let manifest = std::fs::read_to_string(&manifest_path)
+        .map_err(|e| format!("Failed to read {}: {}", manifest_path.display(), e))?;
+
+    let days = register_day(&days, day)
+        .ok_or_else(|| format!("Failed to find the registry in {}", days_path.display()))?;
+    let manifest = add_dependency(&manifest, day).ok_or_else(|| {
+        format!(
+            "Failed to find the day dependencies in {}",
+            manifest_path.display()
+        )
+    })?;
+
+    let files = [
+        (crate_dir.join("Cargo.toml"), render(CARGO_TEMPLATE, day)),
+        (crate_dir.join("src/lib.rs"), render(LIB_TEMPLATE, day)),
+        (crate_dir.join("src/main.rs"), render(MAIN_TEMPLATE, day)),
+        (
+            crate_dir.join("example.txt"),
+            String::from(EXAMPLE_PLACEHOLDER),
+        ),
+        (days_path, days),
+        (manifest_path, manifest),
+    ];
+
+    std::fs::create_dir_all(crate_dir.join("src"))?;
+
+    for (path, content) in &files {
+        std::fs::write(path, content)?;
+    }
+
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAYS: &str = "\
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::SonarSweep>(1),
+    Day::new::<day10::NavigationSubsystem>(10),
+    Day::renderable::<day13::Input>(13),
+];
+";
+
+    const MANIFEST: &str = "\
+[dependencies]
+aoc-common = { path = \"../common\" }
+day1 = { path = \"../day1\" }
+day10 = { path = \"../day10\" }
+day13 = { path = \"../day13\" }
+toml = \"0.8\"
+";
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let days = register_day(DAYS, 11).unwrap();
+
+        assert!(days.contains(
+            "    Day::new::<day10::NavigationSubsystem>(10),\n    Day::new::<day11::Input>(11),\n    Day::renderable::<day13::Input>(13),"
+        ));
+
+        let days = register_day(DAYS, 14).unwrap();
+        assert!(days.contains(
+            "    Day::renderable::<day13::Input>(13),\n    Day::new::<day14::Input>(14),\n];"
+        ));
+    }
+
+    #[test]
+    fn dependencies_are_added_in_order() {
+        let manifest = add_dependency(MANIFEST, 2).unwrap();
+        assert!(manifest
+            .contains("day1 = { path = \"../day1\" }\nday2 = { path = \"../day2\" }\nday10"));
+
+        let manifest = add_dependency(MANIFEST, 25).unwrap();
+        assert!(manifest
+            .contains("day13 = { path = \"../day13\" }\nday25 = { path = \"../day25\" }\ntoml"));
+    }
+
+    #[test]
+    fn crates_are_generated() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        std::fs::create_dir_all(root.join("aoc/src")).unwrap();
+        std::fs::write(root.join("aoc/src/days.rs"), DAYS).unwrap();
+        std::fs::write(root.join("aoc/Cargo.toml"), MANIFEST).unwrap();
+
+        generate(&root, 12).unwrap();
+
+        let manifest = std::fs::read_to_string(root.join("day12/Cargo.toml")).unwrap();
+        let main = std::fs::read_to_string(root.join("day12/src/main.rs")).unwrap();
+        let days = std::fs::read_to_string(root.join("aoc/src/days.rs")).unwrap();
+
+        assert!(manifest.contains("name = \"day12\""));
+        assert!(main.contains("use day12::Input;"));
+        assert!(days.contains("Day::new::<day12::Input>(12)"));
+        assert!(generate(&root, 12).is_err());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,146 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// How an answer compares to the known-good one, if we have it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: String },
+    Unknown,
+}
+
+/// Known-correct answers keyed by day and part (1 or 2), stored as TOML such as:
+///
+/// ```toml
+/// [day1]
+/// part1 = "1451"
+/// part2 = "1395"
+/// ```
+#[derive(Debug)]
+pub struct AnswerStore {
+    path: PathBuf,
+    answers: BTreeMap<u8, [Option<String>; 2]>,
+}
+
+fn index(part: u8) -> usize {
+    match part {
+        1 => 0,
+        2 => 1,
+        _ => panic!("There is no part {}", part),
+    }
+}
+
+impl AnswerStore {
+    /// Loads the store at `path`, starting with an empty one if it doesn't exist yet.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut store = Self {
+            path: path.to_path_buf(),
+            answers: BTreeMap::new(),
+        };
+
+        let content = match std::fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(store),
+            Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e).into()),
+        };
+
+        let table: toml::Table = content
+            .parse()
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+
+        for (key, value) in table {
+            let day = key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or_else(|| format!("Invalid day '{}' in {}", key, path.display()))?;
+
+            for (part, name) in [(1, "part1"), (2, "part2")] {
+                if let Some(answer) = value.get(name) {
+                    let answer = answer
+                        .as_str()
+                        .ok_or_else(|| format!("Answer for {}.{} must be a string", key, name))?;
+
+                    store.record(day, part, answer.to_string());
+                }
+            }
+        }
+
+        Ok(store)
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        self.answers
+            .get(&day)
+            .and_then(|a| a[index(part)].as_deref())
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: String) {
+        self.answers.entry(day).or_default()[index(part)] = Some(answer);
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let mut table = toml::Table::new();
+
+        for (day, answers) in &self.answers {
+            let mut parts = toml::Table::new();
+
+            for (name, answer) in ["part1", "part2"].iter().zip(answers) {
+                if let Some(answer) = answer {
+                    parts.insert(name.to_string(), toml::Value::String(answer.clone()));
+                }
+            }
+
+            table.insert(format!("day{}", day), toml::Value::Table(parts));
+        }
+
+        std::fs::write(&self.path, toml::to_string_pretty(&table)?)?;
+
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_round_trip_in_day_order() {
+        let path = std::env::temp_dir().join(format!("aoc-answers-{}.toml", std::process::id()));
+
+        let mut store = AnswerStore::load(&path).unwrap();
+        store.record(10, 1, String::from("26397"));
+        store.record(2, 2, String::from("900"));
+        store.record(13, 2, String::from("# #\n. #\n"));
+        store.save().unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let store = AnswerStore::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(content.find("[day2]").unwrap() < content.find("[day10]").unwrap());
+
+        assert_eq!(store.check(10, 1, "26397"), Verdict::Pass);
+        assert_eq!(store.check(13, 2, "# #\n. #\n"), Verdict::Pass);
+        assert_eq!(
+            store.check(2, 2, "901"),
+            Verdict::Fail {
+                expected: String::from("900")
+            }
+        );
+        assert_eq!(store.check(2, 1, "150"), Verdict::Unknown);
+    }
+}
@@ -0,0 +1,98 @@
+use std::fmt;
+
+use crate::input::InputError;
+
+/// Everything that can go wrong while parsing an input or solving a puzzle.
+#[derive(Debug)]
+pub enum Error {
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    InvalidCharacter {
+        line: usize,
+        column: usize,
+        character: char,
+    },
+    NoSolution(&'static str),
+    EmptyInput,
+    Unsolved,
+    Input(InputError),
+}
+
+/// Finds the 1-indexed line and column of `remaining` within `input`, which it must be a suffix of.
+pub fn position(input: &str, remaining: &str) -> (usize, usize) {
+    let consumed = &input[..input.len() - remaining.len()];
+    let line = consumed.matches('\n').count() + 1;
+    let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+    let column = consumed[line_start..].chars().count() + 1;
+
+    (line, column)
+}
+
+impl Error {
+    pub fn parse(input: &str, remaining: &str, message: impl Into<String>) -> Self {
+        let (line, column) = position(input, remaining);
+
+        Self::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            Self::InvalidCharacter {
+                line,
+                column,
+                character,
+            } => write!(
+                f,
+                "line {}, column {}: invalid character {:?}",
+                line, column, character
+            ),
+            Self::NoSolution(reason) => write!(f, "no solution found: {}", reason),
+            Self::EmptyInput => write!(f, "the puzzle input is empty"),
+            Self::Unsolved => write!(f, "not solved yet"),
+            Self::Input(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Input(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<InputError> for Error {
+    fn from(e: InputError) -> Self {
+        Self::Input(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions_are_one_indexed() {
+        let input = "1,2\n3,x\n";
+
+        assert_eq!(position(input, input), (1, 1));
+        assert_eq!(position(input, "x\n"), (2, 3));
+        assert_eq!(position(input, ""), (3, 1));
+    }
+}
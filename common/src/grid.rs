@@ -0,0 +1,321 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Error, Result};
+
+/// Position of a cell within a [`Grid`], where `y` counts rows downwards from the top.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Coord {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Coord {
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Dense, fixed size 2D grid stored row by row in a single `Vec`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its rows, which must all be the same (non-zero) length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first()?.len();
+        let height = rows.len();
+
+        if width == 0 || rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            height,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a block of characters, one row per line, converting each with `f`.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        let input = crate::non_empty(input)?.trim_end_matches(['\n', '\r']);
+
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            let start = cells.len();
+
+            for (x, character) in line.chars().enumerate() {
+                let cell = f(character).ok_or(Error::InvalidCharacter {
+                    line: y + 1,
+                    column: x + 1,
+                    character,
+                })?;
+
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - start;
+
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(Error::Parse {
+                        line: y + 1,
+                        column: 1,
+                        message: format!("expected {} cells but found {}", width, row_width),
+                    })
+                }
+                Some(_) => (),
+            }
+
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.x < self.width && coord.y < self.height
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.contains(coord)
+            .then(|| &self.cells[coord.y * self.width + coord.x])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        match self.contains(coord) {
+            true => Some(&mut self.cells[coord.y * self.width + coord.x]),
+            false => None,
+        }
+    }
+
+    /// Every coordinate in the grid, in reading order.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |i| Coord::new(i % width, i / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // An empty grid has no rows, but `chunks` still needs a non-zero size
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells in column `x` from top to bottom, or nothing if it is outside the grid.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        let cells = match x < self.width {
+            true => &self.cells[x..],
+            false => &[],
+        };
+
+        // As with `rows`, `step_by` needs a non-zero step even when there are no columns
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.column(x))
+    }
+
+    fn offset(&self, coord: Coord, (dx, dy): (isize, isize)) -> Option<Coord> {
+        let neighbour = Coord::new(
+            coord.x.checked_add_signed(dx)?,
+            coord.y.checked_add_signed(dy)?,
+        );
+
+        self.contains(neighbour).then_some(neighbour)
+    }
+
+    /// The cells directly above, left, right and below `coord` that are within the grid.
+    pub fn neighbours(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |offset| self.offset(coord, *offset))
+    }
+
+    /// All 8 cells surrounding `coord` (including diagonals) that are within the grid.
+    pub fn surrounding(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |offset| self.offset(coord, *offset))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn filled(width: usize, height: usize, value: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside of a {}x{} grid",
+                coord, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside of a {}x{} grid", coord, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Grid<u32> {
+        Grid::parse(input, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn character_blocks_are_parsed() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Coord::new(2, 0)], 3);
+        assert_eq!(grid[Coord::new(0, 1)], 4);
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+    }
+
+    #[test]
+    fn invalid_blocks_are_rejected() {
+        assert!(matches!(
+            Grid::parse("12\n3x\n", |c| c.to_digit(10)),
+            Err(Error::InvalidCharacter {
+                line: 2,
+                column: 2,
+                character: 'x'
+            })
+        ));
+        assert!(matches!(
+            Grid::parse("12\n345\n", |c| c.to_digit(10)),
+            Err(Error::Parse { line: 2, .. })
+        ));
+        assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_none());
+    }
+
+    #[test]
+    fn rows_and_columns_are_iterated() {
+        let grid = digits("123\n456\n");
+
+        let rows: Vec<_> = grid.rows().map(<[u32]>::to_vec).collect();
+        let columns: Vec<Vec<_>> = grid.columns().map(|c| c.copied().collect()).collect();
+
+        assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+        assert_eq!(columns, vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+        assert_eq!(grid.column(3).count(), 0);
+    }
+
+    #[test]
+    fn empty_grids_have_no_columns() {
+        let grid = Grid::filled(0, 4, 0u8);
+
+        assert_eq!(grid.column(0).count(), 0);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.rows().count(), 0);
+    }
+
+    #[test]
+    fn neighbours_stay_within_bounds() {
+        let grid = digits("123\n456\n789\n");
+
+        let values =
+            |coords: Vec<Coord>| -> Vec<u32> { coords.into_iter().map(|c| grid[c]).collect() };
+
+        assert_eq!(
+            values(grid.neighbours(Coord::new(0, 0)).collect()),
+            vec![2, 4]
+        );
+        assert_eq!(
+            values(grid.neighbours(Coord::new(1, 1)).collect()),
+            vec![2, 4, 6, 8]
+        );
+        assert_eq!(
+            values(grid.surrounding(Coord::new(2, 2)).collect()),
+            vec![5, 6, 8]
+        );
+        assert_eq!(grid.surrounding(Coord::new(1, 1)).count(), 8);
+    }
+}
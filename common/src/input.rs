@@ -0,0 +1,162 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// The year whose puzzle inputs are downloaded and cached.
+pub const YEAR: u16 = 2021;
+
+/// The download cache named by `AOC_CACHE_DIR`, defaulting to `inputs`.
+pub fn cache_dir() -> PathBuf {
+    std::env::var_os("AOC_CACHE_DIR").map_or_else(|| "inputs".into(), PathBuf::from)
+}
+
+/// Where a day's input is kept within the download cache.
+pub fn cached_path(cache_dir: &Path, day: u8) -> PathBuf {
+    cache_dir
+        .join(YEAR.to_string())
+        .join(format!("day{}.txt", day))
+}
+
+/// Finds a day's input in the workspace at `root` without downloading it, preferring an
+/// `input.txt` placed in the day's crate over the cache.
+pub fn find_local(root: &Path, cache_dir: &Path, day: u8) -> Option<PathBuf> {
+    [
+        root.join(format!("day{}/input.txt", day)),
+        cached_path(&root.join(cache_dir), day),
+    ]
+    .into_iter()
+    .find(|path| path.exists())
+}
+
+/// Where a puzzle input should be read from at runtime.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Source {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl Source {
+    /// Interprets a command line argument, treating `-` as stdin and anything else as a path.
+    pub fn from_arg(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::File(PathBuf::from(path)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Self::File(path) => std::fs::read_to_string(path).map_err(|error| InputError {
+                source: self.clone(),
+                error,
+            }),
+            Self::Stdin => {
+                let mut buffer = String::new();
+
+                io::stdin()
+                    .read_to_string(&mut buffer)
+                    .map_err(|error| InputError {
+                        source: self.clone(),
+                        error,
+                    })?;
+
+                Ok(buffer)
+            }
+            Self::Text(text) => Ok(text.clone()),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "stdin"),
+            Self::Text(_) => write!(f, "in-memory input"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    source: Source,
+    error: io::Error,
+}
+
+impl InputError {
+    /// Whether the input simply hasn't been provided, rather than failing to be read.
+    pub fn is_missing(&self) -> bool {
+        self.error.kind() == io::ErrorKind::NotFound
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.source, self.error.kind()) {
+            (Source::File(path), io::ErrorKind::NotFound) => write!(
+                f,
+                "No puzzle input found at {}, either place it there or provide a path (or `-` for stdin)",
+                path.display()
+            ),
+            (source, _) => write!(f, "Failed to read input from {}: {}", source, self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments_are_interpreted() {
+        assert_eq!(Source::from_arg("-"), Source::Stdin);
+        assert_eq!(
+            Source::from_arg("day5/input.txt"),
+            Source::File(PathBuf::from("day5/input.txt"))
+        );
+    }
+
+    #[test]
+    fn missing_files_are_reported() {
+        let source = Source::File(PathBuf::from("does/not/exist.txt"));
+        let error = source.read().unwrap_err();
+
+        assert!(error.to_string().starts_with("No puzzle input found at"));
+    }
+
+    #[test]
+    fn local_inputs_prefer_the_days_crate() {
+        let root = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        let cache = Path::new("cache");
+        let cached = cached_path(&root.join(cache), 3);
+
+        assert_eq!(find_local(&root, cache, 3), None);
+
+        std::fs::create_dir_all(cached.parent().unwrap()).unwrap();
+        std::fs::write(&cached, "00100\n").unwrap();
+        assert_eq!(find_local(&root, cache, 3), Some(cached));
+
+        std::fs::create_dir_all(root.join("day3")).unwrap();
+        std::fs::write(root.join("day3/input.txt"), "00100\n").unwrap();
+        assert_eq!(
+            find_local(&root, cache, 3),
+            Some(root.join("day3/input.txt"))
+        );
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn text_is_returned_as_is() {
+        let source = Source::Text(String::from("1,2,3\n"));
+
+        assert_eq!(source.read().unwrap(), "1,2,3\n");
+    }
+}
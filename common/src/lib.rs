@@ -0,0 +1,50 @@
+use std::fmt;
+
+pub mod answers;
+pub mod error;
+pub mod grid;
+pub mod input;
+pub mod parse;
+pub mod point;
+pub mod render;
+pub mod search;
+pub mod testing;
+
+pub use error::Error;
+pub use input::Source;
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// A single day's puzzle, parsed from its input and then solved in two parts.
+pub trait Solution: Sized {
+    type Part1: fmt::Display;
+    type Part2: fmt::Display;
+
+    fn from_input(input: &str) -> Result<Self>;
+
+    fn load(source: &Source) -> Result<Self> {
+        Self::from_input(&source.read()?)
+    }
+
+    fn part1(&self) -> Result<Self::Part1>;
+
+    fn part2(&self) -> Result<Self::Part2>;
+}
+
+/// Answer type for a part that has not been solved yet, which always returns [`Error::Unsolved`].
+#[derive(Copy, Clone, Debug)]
+pub enum Unsolved {}
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, _: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {}
+    }
+}
+
+/// Rejects inputs that contain nothing but whitespace.
+pub fn non_empty(input: &str) -> Result<&str> {
+    match input.trim().is_empty() {
+        true => Err(Error::EmptyInput),
+        false => Ok(input),
+    }
+}
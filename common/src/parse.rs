@@ -0,0 +1,470 @@
+use std::fmt;
+
+use nom::{
+    branch::alt,
+    character::complete::{line_ending, space0},
+    combinator::{eof, not, recognize},
+    error::ErrorKind,
+    multi::many1,
+    sequence::{delimited, pair, separated_pair, terminated},
+};
+
+use crate::grid::Grid;
+use crate::{Error, Result};
+
+pub type IResult<I, O> = nom::IResult<I, O, ParseError<I>>;
+
+/// What a parser wanted to see at the point it failed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Expected {
+    Tokens(Vec<&'static str>),
+    Char(char),
+    Kind(ErrorKind),
+    Context(&'static str),
+    /// A number too large or small for the named type, such as `an i32`.
+    InRange(&'static str),
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tokens(tokens) if tokens.len() == 1 => write!(f, "`{}`", tokens[0]),
+            Self::Tokens(tokens) => {
+                let tokens: Vec<_> = tokens.iter().map(|t| format!("`{}`", t)).collect();
+                write!(f, "one of {}", tokens.join(", "))
+            }
+            Self::Char('\n') => write!(f, "a newline"),
+            Self::Char(c) => write!(f, "`{}`", c),
+            Self::Kind(ErrorKind::Digit) => write!(f, "a number"),
+            Self::Kind(ErrorKind::Alpha) => write!(f, "a letter"),
+            Self::Kind(ErrorKind::Space | ErrorKind::MultiSpace) => write!(f, "whitespace"),
+            Self::Kind(ErrorKind::Eof) => write!(f, "the end of the input"),
+            Self::Kind(ErrorKind::ManyMN) => write!(f, "the right number of items"),
+            Self::Kind(kind) => write!(f, "{}", kind.description()),
+            Self::Context(context) => write!(f, "{}", context),
+            Self::InRange(ty) => write!(f, "a number that fits in {}", ty),
+        }
+    }
+}
+
+/// Parse error for the day parsers, remembering the furthest position reached and what was expected there.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError<I> {
+    input: I,
+    expected: Expected,
+}
+
+impl<I> nom::error::ParseError<I> for ParseError<I>
+where
+    I: nom::InputLength,
+{
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        Self {
+            input,
+            expected: Expected::Kind(kind),
+        }
+    }
+
+    fn append(_: I, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        Self {
+            input,
+            expected: Expected::Char(c),
+        }
+    }
+
+    fn or(self, other: Self) -> Self {
+        // Prefer whichever alternative got further through the input
+        match self.input.input_len().cmp(&other.input.input_len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => match (self.expected, other.expected) {
+                (Expected::Tokens(mut tokens), Expected::Tokens(others)) => {
+                    tokens.extend(others);
+
+                    Self {
+                        input: other.input,
+                        expected: Expected::Tokens(tokens),
+                    }
+                }
+                (_, expected) => Self {
+                    input: other.input,
+                    expected,
+                },
+            },
+        }
+    }
+}
+
+impl<I> nom::error::ContextError<I> for ParseError<I>
+where
+    I: nom::InputLength,
+{
+    fn add_context(input: I, context: &'static str, other: Self) -> Self {
+        // Only describe failures that happened right at the start of the context
+        match input.input_len() == other.input.input_len() {
+            true => Self {
+                input,
+                expected: Expected::Context(context),
+            },
+            false => other,
+        }
+    }
+}
+
+/// Matches a literal, reporting it as the expected token on failure.
+pub fn tag<'a>(token: &'static str) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    move |input: &'a str| {
+        nom::bytes::complete::tag(token)(input).map_err(|e: nom::Err<ParseError<&str>>| {
+            e.map(|e| ParseError {
+                input: e.input,
+                expected: Expected::Tokens(vec![token]),
+            })
+        })
+    }
+}
+
+/// A single line break that isn't followed by a blank line or the end of the input.
+pub fn line_break(input: &str) -> IResult<&str, &str> {
+    terminated(line_ending, not(alt((line_ending, eof))))(input)
+}
+
+/// An empty line between two sections of the input.
+pub fn blank_line(input: &str) -> IResult<&str, &str> {
+    terminated(recognize(pair(line_ending, line_ending)), not(eof))(input)
+}
+
+/// A comma, optionally surrounded by spaces.
+pub fn comma(input: &str) -> IResult<&str, &str> {
+    delimited(space0, tag(","), space0)(input)
+}
+
+/// One or more `item`s split by `separator`. Unlike `separated_list1`, an item that fails to
+/// parse after a separator is reported rather than quietly ending the list there.
+pub fn separated<'a, O, S, P>(
+    mut separator: S,
+    mut item: P,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    S: FnMut(&'a str) -> IResult<&'a str, &'a str>,
+    P: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    move |input: &'a str| {
+        let (mut input, first) = item(input)?;
+        let mut items = vec![first];
+
+        while let Ok((rest, _)) = separator(input) {
+            let (rest, next) = item(rest)?;
+
+            items.push(next);
+            input = rest;
+        }
+
+        Ok((input, items))
+    }
+}
+
+/// Comma separated values on a single line, such as `3,4,3,1,2`.
+pub fn comma_separated<'a, O, P>(item: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    separated(comma, item)
+}
+
+/// One `item` per line, stopping before a blank line or the final newline.
+pub fn lines<'a, O, P>(item: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    separated(line_break, item)
+}
+
+/// Sections of the input separated by blank lines, such as bingo boards.
+pub fn blocks<'a, O, P>(item: P) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    separated(blank_line, item)
+}
+
+/// Two values separated by a comma, such as `0,9`.
+pub fn comma_pair<'a, A, B, P, Q>(
+    first: P,
+    second: Q,
+) -> impl FnMut(&'a str) -> IResult<&'a str, (A, B)>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, A>,
+    Q: FnMut(&'a str) -> IResult<&'a str, B>,
+{
+    separated_pair(first, comma, second)
+}
+
+/// Runs one of nom's number parsers, telling numbers that don't fit in its type apart from
+/// input that isn't a number at all.
+fn in_range<'a, O>(
+    ty: &'static str,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, O>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O> {
+    move |input: &'a str| {
+        parser(input).map_err(|e| {
+            e.map(|e| match e.expected {
+                Expected::Kind(ErrorKind::Digit) if number_at(e.input).is_some() => ParseError {
+                    input: e.input,
+                    expected: Expected::InRange(ty),
+                },
+                _ => e,
+            })
+        })
+    }
+}
+
+/// The optionally signed run of digits at the start of `input`, if there is one.
+fn number_at(input: &str) -> Option<&str> {
+    let unsigned = input.strip_prefix(['+', '-']).unwrap_or(input);
+    let digits = unsigned.len()
+        - unsigned
+            .trim_start_matches(|c: char| c.is_ascii_digit())
+            .len();
+
+    match digits {
+        0 => None,
+        _ => Some(&input[..input.len() - unsigned.len() + digits]),
+    }
+}
+
+/// A decimal `i32`, optionally signed.
+pub fn i32(input: &str) -> IResult<&str, i32> {
+    in_range("an i32", nom::character::complete::i32)(input)
+}
+
+/// A decimal `i64`, optionally signed.
+pub fn i64(input: &str) -> IResult<&str, i64> {
+    in_range("an i64", nom::character::complete::i64)(input)
+}
+
+/// A decimal `u32`.
+pub fn u32(input: &str) -> IResult<&str, u32> {
+    in_range("a u32", nom::character::complete::u32)(input)
+}
+
+/// A single decimal digit.
+pub fn digit(input: &str) -> IResult<&str, u32> {
+    match input.chars().next().and_then(|c| c.to_digit(10)) {
+        Some(digit) => Ok((&input[1..], digit)),
+        None => Err(nom::Err::Error(ParseError {
+            input,
+            expected: Expected::Context("a digit"),
+        })),
+    }
+}
+
+/// Lines of single digits, all of the same width, such as a height map.
+pub fn digit_grid(input: &str) -> IResult<&str, Grid<u32>> {
+    let mut width = None;
+
+    let row = |input| {
+        let (rest, row) = many1(digit)(input)?;
+
+        match *width.get_or_insert(row.len()) == row.len() {
+            true => Ok((rest, row)),
+            false => Err(nom::Err::Failure(ParseError {
+                input,
+                expected: Expected::Context("a row as wide as the first"),
+            })),
+        }
+    };
+
+    let (input, rows) = lines(row)(input)?;
+
+    // Every row has been checked against the first, so this can't fail
+    let grid = Grid::from_rows(rows).expect("rows have equal widths");
+
+    Ok((input, grid))
+}
+
+fn describe_next(input: &str, expected: &Expected) -> String {
+    if let (Expected::InRange(_), Some(number)) = (expected, number_at(input)) {
+        return format!("`{}`", number);
+    }
+
+    match input.chars().next() {
+        Some('\n') => String::from("a newline"),
+        Some(c) => format!("`{}`", c),
+        None => String::from("the end of the input"),
+    }
+}
+
+/// Runs a parser over the whole input, converting failures into positioned errors and
+/// rejecting anything other than trailing whitespace that it left unconsumed.
+pub fn finish<'a, O, P>(input: &'a str, mut parser: P) -> Result<O>
+where
+    P: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    if input.trim().is_empty() {
+        return Err(Error::EmptyInput);
+    }
+
+    match parser(input) {
+        Ok((remaining, output)) => {
+            let remaining = remaining.trim_start();
+
+            match remaining.lines().next() {
+                None => Ok(output),
+                Some(line) => Err(Error::parse(
+                    input,
+                    remaining,
+                    format!("unexpected trailing input `{}`", line),
+                )),
+            }
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => Err(Error::parse(
+            input,
+            e.input,
+            format!(
+                "expected {}, found {}",
+                e.expected,
+                describe_next(e.input, &e.expected)
+            ),
+        )),
+        Err(nom::Err::Incomplete(_)) => Err(Error::parse(input, "", "unexpected end of input")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nom::branch::alt;
+    use nom::character::complete::newline;
+    use nom::multi::separated_list1;
+    use nom::sequence::separated_pair;
+
+    use super::*;
+
+    fn pair(input: &str) -> IResult<&str, (u32, u32)> {
+        separated_pair(u32, tag(","), u32)(input)
+    }
+
+    fn message(error: Error) -> String {
+        error.to_string()
+    }
+
+    #[test]
+    fn expected_tokens_are_reported() {
+        let error = finish("1;2", pair).unwrap_err();
+
+        assert_eq!(message(error), "line 1, column 2: expected `,`, found `;`");
+    }
+
+    #[test]
+    fn alternatives_are_combined() {
+        let error = finish("left", alt((tag("up"), tag("down")))).unwrap_err();
+
+        assert_eq!(
+            message(error),
+            "line 1, column 1: expected one of `up`, `down`, found `l`"
+        );
+    }
+
+    #[test]
+    fn numbers_are_described() {
+        let error = finish("1,x", pair).unwrap_err();
+
+        assert_eq!(
+            message(error),
+            "line 1, column 3: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn numbers_out_of_range_are_described() {
+        assert_eq!(
+            message(finish("0,3000000000", comma_separated(i32)).unwrap_err()),
+            "line 1, column 3: expected a number that fits in an i32, found `3000000000`"
+        );
+        assert_eq!(
+            message(finish("-1", u32).unwrap_err()),
+            "line 1, column 1: expected a number that fits in a u32, found `-1`"
+        );
+        assert_eq!(
+            message(finish("-x", i32).unwrap_err()),
+            "line 1, column 1: expected a number, found `-`"
+        );
+        assert_eq!(
+            message(finish("-99999999999999999999", i64).unwrap_err()),
+            "line 1, column 1: expected a number that fits in an i64, found `-99999999999999999999`"
+        );
+        assert_eq!(finish("-12", i32).unwrap(), -12);
+    }
+
+    #[test]
+    fn trailing_input_is_rejected() {
+        let error = finish("1,2\n3,4\n5;6\n", separated_list1(newline, pair)).unwrap_err();
+
+        assert_eq!(
+            message(error),
+            "line 3, column 1: unexpected trailing input `5;6`"
+        );
+    }
+
+    #[test]
+    fn trailing_whitespace_is_allowed() {
+        let pairs = finish("1,2\n3,4\n\n", separated_list1(newline, pair)).unwrap();
+
+        assert_eq!(pairs, vec![(1, 2), (3, 4)]);
+    }
+
+    #[test]
+    fn contexts_describe_failures_at_their_start() {
+        let mut parser = nom::error::context("a pair", pair);
+
+        assert_eq!(
+            message(finish("x", &mut parser).unwrap_err()),
+            "line 1, column 1: expected a pair, found `x`"
+        );
+        assert_eq!(
+            message(finish("1,x", &mut parser).unwrap_err()),
+            "line 1, column 3: expected a number, found `x`"
+        );
+    }
+
+    #[test]
+    fn lists_report_bad_items() {
+        let error = finish("1,2\n3;4\n", lines(pair)).unwrap_err();
+
+        assert_eq!(message(error), "line 2, column 2: expected `,`, found `;`");
+        assert_eq!(
+            finish("1, 2,3", comma_separated(u32)).unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn blocks_are_split_on_blank_lines() {
+        let parser = blocks(lines(comma_pair(u32, u32)));
+        let sections = finish("1,2\n3,4\n\n5,6\n\n", parser).unwrap();
+
+        assert_eq!(sections, vec![vec![(1, 2), (3, 4)], vec![(5, 6)]]);
+    }
+
+    #[test]
+    fn digit_grids_must_be_rectangular() {
+        let grid = finish("123\n456\n", digit_grid).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+
+        let error = finish("123\n45\n", digit_grid).unwrap_err();
+
+        assert_eq!(
+            message(error),
+            "line 2, column 1: expected a row as wide as the first, found `4`"
+        );
+    }
+
+    #[test]
+    fn empty_input_is_rejected() {
+        assert!(matches!(finish("\n", pair), Err(Error::EmptyInput)));
+    }
+}
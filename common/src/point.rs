@@ -0,0 +1,253 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use nom::combinator::map;
+
+use crate::grid::Coord;
+use crate::parse::{self, comma_pair, IResult};
+
+/// One of the axes of the plane, for reflections.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Axis {
+    X,
+    Y,
+}
+
+/// Integer point (or vector) in 2D space.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Integer point (or vector) in 3D space.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Point3 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Self = Self::new(0, 0);
+
+    pub const fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    /// Parses an `x,y` pair.
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let number = parse::i32;
+
+        map(comma_pair(number, number), |(x, y)| Self::new(x, y))(input)
+    }
+
+    pub fn manhattan(self, other: Self) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i32 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Mirrors the point in the line `axis = position`, such as folding along `x = 5`, or
+    /// `None` if the reflection is out of range.
+    pub fn reflect(self, axis: Axis, position: i32) -> Option<Self> {
+        let mirror = |value: i32| position.checked_mul(2)?.checked_sub(value);
+
+        match axis {
+            Axis::X => Some(Self::new(mirror(self.x)?, self.y)),
+            Axis::Y => Some(Self::new(self.x, mirror(self.y)?)),
+        }
+    }
+
+    pub fn get(self, axis: Axis) -> i32 {
+        match axis {
+            Axis::X => self.x,
+            Axis::Y => self.y,
+        }
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Self = Self::new(0, 0, 0);
+
+    pub const fn new(x: i32, y: i32, z: i32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Parses an `x,y,z` triple.
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let number = parse::i32;
+
+        map(
+            comma_pair(number, comma_pair(number, number)),
+            |(x, (y, z))| Self::new(x, y, z),
+        )(input)
+    }
+
+    pub fn manhattan(self, other: Self) -> i32 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Self) -> i32 {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+}
+
+macro_rules! impl_arithmetic {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                Self { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<i32> for $point {
+            type Output = Self;
+
+            fn mul(self, scale: i32) -> Self {
+                Self { $($field: self.$field * scale),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+impl_arithmetic!(Point { x, y });
+impl_arithmetic!(Point3 { x, y, z });
+
+impl TryFrom<Point> for Coord {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(point: Point) -> Result<Self, Self::Error> {
+        Ok(Self::new(
+            usize::try_from(point.x)?,
+            usize::try_from(point.y)?,
+        ))
+    }
+}
+
+/// Smallest axis aligned rectangle containing a set of points, inclusive at both ends.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BoundingBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl BoundingBox {
+    /// Returns `None` if there are no points to bound.
+    pub fn from_points(points: impl IntoIterator<Item = Point>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+
+        Some(points.fold(
+            Self {
+                min: first,
+                max: first,
+            },
+            |bounds, p| Self {
+                min: Point::new(bounds.min.x.min(p.x), bounds.min.y.min(p.y)),
+                max: Point::new(bounds.max.x.max(p.x), bounds.max.y.max(p.y)),
+            },
+        ))
+    }
+
+    pub fn width(&self) -> i32 {
+        self.max.x - self.min.x + 1
+    }
+
+    pub fn height(&self) -> i32 {
+        self.max.y - self.min.y + 1
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::finish;
+
+    #[test]
+    fn points_are_parsed() {
+        assert_eq!(finish("3,-4", Point::parse).unwrap(), Point::new(3, -4));
+        assert_eq!(
+            finish("1,2,3", Point3::parse).unwrap(),
+            Point3::new(1, 2, 3)
+        );
+        assert!(finish("1;2", Point::parse).is_err());
+    }
+
+    #[test]
+    fn distances() {
+        let (a, b) = (Point::new(1, 1), Point::new(4, -3));
+
+        assert_eq!(a + b, Point::new(5, -2));
+        assert_eq!(a - b, Point::new(-3, 4));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(Point3::new(1, 2, 3).manhattan(-Point3::new(1, 2, 3)), 12);
+    }
+
+    #[test]
+    fn reflections() {
+        assert_eq!(
+            Point::new(6, 10).reflect(Axis::Y, 7),
+            Some(Point::new(6, 4))
+        );
+        assert_eq!(
+            Point::new(6, 10).reflect(Axis::X, 5),
+            Some(Point::new(4, 10))
+        );
+        assert_eq!(
+            Point::new(1_600_000_000, 1).reflect(Axis::X, 1_500_000_000),
+            None
+        );
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let points = [Point::new(2, 5), Point::new(-1, 3), Point::new(4, 4)];
+        let bounds = BoundingBox::from_points(points).unwrap();
+
+        assert_eq!(bounds.min, Point::new(-1, 3));
+        assert_eq!(bounds.max, Point::new(4, 5));
+        assert_eq!((bounds.width(), bounds.height()), (6, 3));
+        assert!(bounds.contains(Point::new(0, 4)));
+        assert!(BoundingBox::from_points([]).is_none());
+    }
+}
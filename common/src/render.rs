@@ -0,0 +1,236 @@
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::grid::Grid;
+
+/// A colour with 8 bits per channel.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+
+    pub const fn grey(level: u8) -> Self {
+        Self(level, level, level)
+    }
+
+    /// Blends from `self` to `other`, where `t` runs from 0 to 1.
+    pub fn lerp(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let channel =
+            |a: u8, b: u8| (f64::from(a) + (f64::from(b) - f64::from(a)) * t).round() as u8;
+
+        Self(
+            channel(self.0, other.0),
+            channel(self.1, other.1),
+            channel(self.2, other.2),
+        )
+    }
+}
+
+/// Image file formats that frames can be written in.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum ImageFormat {
+    /// Binary PPM, which needs no extra dependencies but few viewers open.
+    #[default]
+    Ppm,
+    /// PNG, available with the `png` feature.
+    #[cfg(feature = "png")]
+    Png,
+}
+
+impl ImageFormat {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Ppm => "ppm",
+            #[cfg(feature = "png")]
+            Self::Png => "png",
+        }
+    }
+}
+
+/// An RGB image, such as a rendering of a [`Grid`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Draws each cell of `grid` as a `scale` by `scale` square coloured by `colour`.
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, colour: impl Fn(&T) -> Rgb) -> Self {
+        let width = grid.width() * scale;
+        let height = grid.height() * scale;
+
+        let pixels = grid
+            .rows()
+            .flat_map(|row| {
+                let line: Vec<_> = row
+                    .iter()
+                    .flat_map(|cell| std::iter::repeat_n(colour(cell), scale))
+                    .collect();
+
+                std::iter::repeat_n(line, scale).flatten()
+            })
+            .collect();
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|Rgb(r, g, b)| [*r, *g, *b])
+            .collect()
+    }
+
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        writer.write_all(&self.bytes())
+    }
+
+    #[cfg(feature = "png")]
+    pub fn write_png(&self, writer: impl Write) -> io::Result<()> {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.bytes()))
+            .map_err(io::Error::other)
+    }
+
+    pub fn write(&self, format: ImageFormat, writer: impl Write) -> io::Result<()> {
+        match format {
+            ImageFormat::Ppm => self.write_ppm(writer),
+            #[cfg(feature = "png")]
+            ImageFormat::Png => self.write_png(writer),
+        }
+    }
+
+    pub fn save(&self, format: ImageFormat, path: &Path) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+
+        self.write(format, &mut writer)?;
+        writer.flush()
+    }
+}
+
+/// Writes numbered images into a directory, one per step of a simulation.
+#[derive(Debug)]
+pub struct Frames {
+    dir: PathBuf,
+    prefix: String,
+    format: ImageFormat,
+    next: usize,
+}
+
+impl Frames {
+    pub fn new(dir: impl Into<PathBuf>, prefix: &str, format: ImageFormat) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+
+        Ok(Self {
+            dir,
+            prefix: prefix.to_string(),
+            format,
+            next: 0,
+        })
+    }
+
+    /// Saves the next frame, returning where it was written.
+    pub fn push(&mut self, image: &Image) -> io::Result<PathBuf> {
+        let name = format!(
+            "{}{:04}.{}",
+            self.prefix,
+            self.next,
+            self.format.extension()
+        );
+        let path = self.dir.join(name);
+
+        image.save(self.format, &path)?;
+        self.next += 1;
+
+        Ok(path)
+    }
+}
+
+/// Days whose state can be drawn, as one image per step.
+pub trait Render {
+    fn frames(&self) -> Vec<Image>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Image {
+        let grid = Grid::parse("#.\n.#\n", |c| Some(c == '#')).unwrap();
+
+        Image::from_grid(&grid, 2, |set| match set {
+            true => Rgb::WHITE,
+            false => Rgb::BLACK,
+        })
+    }
+
+    #[test]
+    fn grids_are_scaled() {
+        let image = checkerboard();
+
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(
+            image.pixels[..4],
+            [Rgb::WHITE, Rgb::WHITE, Rgb::BLACK, Rgb::BLACK]
+        );
+        assert_eq!(
+            image.pixels[12..],
+            [Rgb::BLACK, Rgb::BLACK, Rgb::WHITE, Rgb::WHITE]
+        );
+    }
+
+    #[test]
+    fn ppm_has_a_header_and_raw_pixels() {
+        let mut bytes = Vec::new();
+        checkerboard().write_ppm(&mut bytes).unwrap();
+
+        let header = b"P6\n4 4\n255\n";
+
+        assert_eq!(&bytes[..header.len()], header);
+        assert_eq!(bytes.len(), header.len() + 4 * 4 * 3);
+        assert_eq!(bytes[header.len()..header.len() + 3], [255, 255, 255]);
+    }
+
+    #[test]
+    fn colours_blend() {
+        assert_eq!(Rgb::BLACK.lerp(Rgb::WHITE, 0.5), Rgb::grey(128));
+        assert_eq!(Rgb::BLACK.lerp(Rgb::WHITE, 2.0), Rgb::WHITE);
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn png_has_a_signature() {
+        let mut bytes = Vec::new();
+        checkerboard().write_png(&mut bytes).unwrap();
+
+        assert_eq!(
+            bytes[..8],
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']
+        );
+    }
+}
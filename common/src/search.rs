@@ -0,0 +1,270 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Route found by a search, from the start node to the goal inclusive.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// Nodes seen so far, stored once each and referred to by index.
+struct Visited<N> {
+    nodes: Vec<N>,
+    parents: Vec<usize>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Visited<N> {
+    fn new(start: N) -> Self {
+        Self {
+            nodes: vec![start.clone()],
+            parents: vec![0],
+            indices: HashMap::from([(start, 0)]),
+        }
+    }
+
+    /// Returns the index of `node`, and whether it was seen for the first time.
+    fn insert(&mut self, node: N, parent: usize) -> (usize, bool) {
+        match self.indices.get(&node) {
+            Some(index) => (*index, false),
+            None => {
+                let index = self.nodes.len();
+
+                self.indices.insert(node.clone(), index);
+                self.nodes.push(node);
+                self.parents.push(parent);
+
+                (index, true)
+            }
+        }
+    }
+
+    fn path<C>(&self, mut index: usize, cost: C) -> Path<N, C> {
+        let mut nodes = vec![self.nodes[index].clone()];
+
+        while index != 0 {
+            index = self.parents[index];
+            nodes.push(self.nodes[index].clone());
+        }
+
+        nodes.reverse();
+
+        Path { nodes, cost }
+    }
+}
+
+/// Every node reachable from `start` (including itself), in the order a breadth first search finds them.
+pub fn reachable<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::from([start.clone()]);
+    let mut order = vec![start];
+    let mut next = 0;
+
+    while let Some(node) = order.get(next) {
+        let found: Vec<_> = neighbours(node)
+            .into_iter()
+            .filter(|n| seen.insert(n.clone()))
+            .collect();
+
+        order.extend(found);
+        next += 1;
+    }
+
+    order
+}
+
+/// Breadth first search for the fewest steps from `start` to a goal, where each step costs 1.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([(0, 0)]);
+
+    while let Some((index, steps)) = queue.pop_front() {
+        if is_goal(&visited.nodes[index]) {
+            return Some(visited.path(index, steps));
+        }
+
+        for neighbour in neighbours(&visited.nodes[index]) {
+            if let (next, true) = visited.insert(neighbour, index) {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// Entry in the open set, ordered so the `BinaryHeap` pops the lowest estimate first.
+struct Candidate<C> {
+    estimate: C,
+    cost: C,
+    index: usize,
+}
+
+impl<C: Ord> Ord for Candidate<C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+impl<C: Ord> PartialOrd for Candidate<C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<C: Ord> PartialEq for Candidate<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<C: Ord> Eq for Candidate<C> {}
+
+/// Cheapest path from `start` to a goal, where `neighbours` yields each reachable node with the cost of moving to it.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but guided by `heuristic`, which must never overestimate the remaining cost.
+pub fn astar<N, C, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Default + Ord + Add<Output = C>,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new(start);
+    let mut costs = vec![C::default()];
+    let mut open = BinaryHeap::from([Candidate {
+        estimate: heuristic(&visited.nodes[0]),
+        cost: C::default(),
+        index: 0,
+    }]);
+
+    while let Some(Candidate { cost, index, .. }) = open.pop() {
+        // Skip anything that has since been reached more cheaply
+        if cost > costs[index] {
+            continue;
+        }
+
+        if is_goal(&visited.nodes[index]) {
+            return Some(visited.path(index, cost));
+        }
+
+        for (neighbour, step) in neighbours(&visited.nodes[index]) {
+            let cost = cost + step;
+            let (next, is_new) = visited.insert(neighbour, index);
+
+            if is_new {
+                costs.push(cost);
+            } else if cost < costs[next] {
+                costs[next] = cost;
+                visited.parents[next] = index;
+            } else {
+                continue;
+            }
+
+            open.push(Candidate {
+                estimate: cost + heuristic(&visited.nodes[next]),
+                cost,
+                index: next,
+            });
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Coord, Grid};
+
+    const RISKS: &str = "\
+1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581
+";
+
+    fn risks() -> Grid<u32> {
+        Grid::parse(RISKS, |c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn reachable_nodes_are_found_once() {
+        let nodes = reachable(0, |n| [(n + 1) % 5, (n + 2) % 5]);
+
+        assert_eq!(nodes, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn bfs_finds_fewest_steps() {
+        let grid = risks();
+        let goal = Coord::new(9, 9);
+
+        let path = bfs(Coord::new(0, 0), |c| grid.neighbours(*c), |c| *c == goal).unwrap();
+
+        assert_eq!(path.cost, 18);
+        assert_eq!(path.nodes.len(), 19);
+        assert_eq!(path.nodes.last(), Some(&goal));
+        assert!(bfs(0, |n| [n + 1].into_iter().filter(|n| *n < 5), |n| *n == 10).is_none());
+    }
+
+    #[test]
+    fn weighted_searches_find_lowest_cost() {
+        let grid = risks();
+        let goal = Coord::new(9, 9);
+        let neighbours = |c: &Coord| {
+            grid.neighbours(*c)
+                .map(|n| (n, grid[n]))
+                .collect::<Vec<_>>()
+        };
+
+        let path = dijkstra(Coord::new(0, 0), neighbours, |c| *c == goal).unwrap();
+
+        assert_eq!(path.cost, 40);
+        assert_eq!(path.nodes.iter().skip(1).map(|c| grid[*c]).sum::<u32>(), 40);
+
+        let distance = |c: &Coord| (goal.x - c.x + goal.y - c.y) as u32;
+        let path = astar(Coord::new(0, 0), neighbours, distance, |c| *c == goal).unwrap();
+
+        assert_eq!(path.cost, 40);
+    }
+}
@@ -0,0 +1,48 @@
+use std::path::Path;
+
+use crate::answers::AnswerStore;
+use crate::{input, Solution, Source};
+
+/// Checks a day against the answers recorded for a real puzzle input by `aoc check --record`.
+///
+/// `dir` is the day's crate, such as `day13`. Answers are read from `answers.toml` in the
+/// workspace above it, and the input is found the same way the runner finds it. The check is
+/// skipped if there are no answers for the day or no input to check them against.
+pub fn check_local_answers<S: Solution>(dir: &str) {
+    let dir = Path::new(dir);
+    let root = dir.parent().expect("days are kept inside the workspace");
+
+    let day: u8 = dir
+        .file_name()
+        .and_then(|name| name.to_str()?.strip_prefix("day")?.parse().ok())
+        .unwrap_or_else(|| panic!("{} is not a day's crate", dir.display()));
+
+    let store = AnswerStore::load(&root.join("answers.toml")).unwrap();
+    let expected = [store.get(day, 1), store.get(day, 2)];
+
+    if expected.iter().all(Option::is_none) {
+        return;
+    }
+
+    let Some(path) = input::find_local(root, &input::cache_dir(), day) else {
+        return;
+    };
+
+    let solution = S::load(&Source::File(path)).unwrap();
+
+    if let Some(expected) = expected[0] {
+        assert_eq!(
+            solution.part1().unwrap().to_string(),
+            expected,
+            "Part 1 is incorrect"
+        );
+    }
+
+    if let Some(expected) = expected[1] {
+        assert_eq!(
+            solution.part2().unwrap().to_string(),
+            expected,
+            "Part 2 is incorrect"
+        );
+    }
+}
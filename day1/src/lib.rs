@@ -0,0 +1,82 @@
+use aoc_common::{Result, Solution};
+
+pub mod log;
+pub mod stats;
+pub mod stream;
+
+use log::{DepthLog, Mode};
+use stats::Trend;
+use stream::WindowCounter;
+
+/// Counts the values that are larger than the one before them.
+pub fn calculate_larger_pairs(values: &[u64]) -> usize {
+    values.windows(2).filter(|v| v[0] < v[1]).count()
+}
+
+/// Depth measurements from the sonar sweep, in the order they were taken.
+#[derive(Debug)]
+pub struct SonarSweep {
+    depths: Vec<u64>,
+}
+
+impl SonarSweep {
+    pub fn new(depths: Vec<u64>) -> Self {
+        Self { depths }
+    }
+
+    pub fn depths(&self) -> &[u64] {
+        &self.depths
+    }
+
+    /// Counts the sums of `window` consecutive depths that are larger than the sum before them.
+    pub fn window_increases(&self, window: usize) -> usize {
+        let mut counter = WindowCounter::new(window);
+        counter.extend(self.depths.iter().copied());
+
+        counter.increases()
+    }
+
+    /// Summarises how the depth changes through the sweep, see [`Trend`].
+    pub fn trend(&self, bucket_width: u64) -> Trend {
+        Trend::new(&self.depths, bucket_width)
+    }
+}
+
+impl Solution for SonarSweep {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn from_input(input: &str) -> Result<Self> {
+        let log = DepthLog::parse(aoc_common::non_empty(input)?, Mode::Strict)?;
+
+        Ok(Self { depths: log.depths })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(calculate_larger_pairs(&self.depths))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        Ok(self.window_increases(3))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let sweep = SonarSweep::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(sweep.part1().unwrap(), 7);
+        assert_eq!(sweep.part2().unwrap(), 5);
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<SonarSweep>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+use std::num::ParseIntError;
+
+use aoc_common::{Error, Result};
+
+/// Reads the depth on one line of a log, or `None` for lines that are blank or only a comment.
+///
+/// Comments start with `#` and run to the end of the line, and surrounding whitespace
+/// (including the `\r` of Windows line endings) is ignored.
+pub fn parse_line(line: &str) -> Option<std::result::Result<u64, ParseIntError>> {
+    let content = line.split('#').next().unwrap_or_default().trim();
+
+    (!content.is_empty()).then(|| content.parse())
+}
+
+/// How to treat lines that aren't a depth.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum Mode {
+    /// Skip them and list them in the log's report.
+    #[default]
+    Lenient,
+    /// Fail on the first one.
+    Strict,
+}
+
+/// A line that couldn't be read as a depth.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidLine {
+    /// 1-indexed line number.
+    pub line: usize,
+    pub text: String,
+    pub reason: String,
+}
+
+impl fmt::Display for InvalidLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: invalid depth {:?} ({})",
+            self.line, self.text, self.reason
+        )
+    }
+}
+
+/// The depths read from a log, along with any lines that had to be skipped.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DepthLog {
+    pub depths: Vec<u64>,
+    pub invalid: Vec<InvalidLine>,
+}
+
+impl DepthLog {
+    pub fn parse(input: &str, mode: Mode) -> Result<Self> {
+        let mut log = Self::default();
+
+        for (i, text) in input.lines().enumerate() {
+            match parse_line(text) {
+                None => {}
+                Some(Ok(depth)) => log.depths.push(depth),
+                Some(Err(e)) => {
+                    let invalid = InvalidLine {
+                        line: i + 1,
+                        text: text.trim().to_string(),
+                        reason: e.to_string(),
+                    };
+
+                    if mode == Mode::Strict {
+                        return Err(Error::Parse {
+                            line: invalid.line,
+                            column: 1,
+                            message: format!("invalid depth {:?} ({})", invalid.text, e),
+                        });
+                    }
+
+                    log.invalid.push(invalid);
+                }
+            }
+        }
+
+        Ok(log)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LOG: &str = "depth\r\n199\r\n\r\n# calibration\r\n200 # surfaced\r\n20o\r\n208\r\n\r\n";
+
+    #[test]
+    fn lenient_logs_report_invalid_lines() {
+        let log = DepthLog::parse(LOG, Mode::Lenient).unwrap();
+
+        assert_eq!(log.depths, [199, 200, 208]);
+        assert_eq!(
+            log.invalid.iter().map(|l| l.line).collect::<Vec<_>>(),
+            [1, 6]
+        );
+        assert_eq!(
+            log.invalid[1].to_string(),
+            "line 6: invalid depth \"20o\" (invalid digit found in string)"
+        );
+    }
+
+    #[test]
+    fn strict_logs_fail_on_the_first_invalid_line() {
+        let error = DepthLog::parse(LOG, Mode::Strict).unwrap_err();
+
+        assert!(matches!(error, Error::Parse { line: 1, .. }));
+        assert!(DepthLog::parse("199\r\n# done\r\n\r\n", Mode::Strict).is_ok());
+    }
+}
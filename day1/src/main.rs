@@ -1,27 +1,44 @@
-use std::str::FromStr;
+use aoc_common::{Solution, Source};
+use day1::log::{DepthLog, Mode};
+use day1::SonarSweep;
 
-fn calculate_larger_pairs(values: &[u64]) -> usize {
-    values.windows(2).filter(|v| v[0] < v[1]).count()
-}
+const USAGE: &str = "Usage: day1 [input] [--strict] [--stats [--json]]";
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let input: Vec<_> = std::fs::read_to_string("input.txt")?
-        .lines()
-        .map(|line| u64::from_str(line).unwrap())
-        .collect();
-
-    let instances = calculate_larger_pairs(&input);
-
-    println!("Part 1: {}", instances);
-
-    let values: Vec<_> = input
-        .windows(3)
-        .map(|slice| slice.iter().sum::<u64>())
-        .collect();
-
-    let instances = calculate_larger_pairs(&values);
-
-    println!("Part 2: {}", instances);
+    let mut source = Source::File("input.txt".into());
+    let mut mode = Mode::Lenient;
+    let (mut stats, mut json) = (false, false);
+
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--strict" => mode = Mode::Strict,
+            "--stats" => stats = true,
+            "--json" => json = true,
+            flag if flag.starts_with("--") => return Err(USAGE.into()),
+            arg => source = Source::from_arg(arg),
+        }
+    }
+
+    if json && !stats {
+        return Err(USAGE.into());
+    }
+
+    let log = DepthLog::parse(&source.read()?, mode)?;
+
+    for invalid in &log.invalid {
+        eprintln!("Skipped {}", invalid);
+    }
+
+    let sonar_sweep = SonarSweep::new(log.depths);
+
+    match (stats, json) {
+        (false, _) => {
+            println!("Part 1: {}", sonar_sweep.part1()?);
+            println!("Part 2: {}", sonar_sweep.part2()?);
+        }
+        (true, false) => print!("{}", sonar_sweep.trend(10)),
+        (true, true) => println!("{}", sonar_sweep.trend(10).to_json()),
+    }
 
     Ok(())
 }
@@ -0,0 +1,222 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::calculate_larger_pairs;
+
+/// The biggest change in depth between two neighbouring readings.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Jump {
+    /// 0-indexed position of the reading the jump starts from.
+    pub index: usize,
+    pub from: u64,
+    pub to: u64,
+}
+
+impl Jump {
+    /// The change in depth, which is wider than the depths so that it can't overflow.
+    pub fn delta(&self) -> i128 {
+        i128::from(self.to) - i128::from(self.from)
+    }
+}
+
+/// How many deltas fell in `[from, to)`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct Bucket {
+    pub from: i128,
+    pub to: i128,
+    pub count: usize,
+}
+
+/// How the depth changes from one reading to the next across a sweep.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize)]
+pub struct Trend {
+    pub readings: usize,
+    pub increases: usize,
+    pub decreases: usize,
+    /// Neighbouring readings at the same depth, which don't count as increases.
+    pub plateaus: usize,
+    pub longest_increasing_run: usize,
+    pub longest_decreasing_run: usize,
+    pub longest_plateau: usize,
+    pub largest_jump: Option<Jump>,
+    /// Deltas grouped into buckets of equal width, in order and leaving out empty ones.
+    pub histogram: Vec<Bucket>,
+}
+
+/// Tracks the longest streak of consecutive matching steps.
+#[derive(Default)]
+struct Run {
+    current: usize,
+    longest: usize,
+}
+
+impl Run {
+    fn step(&mut self, matches: bool) {
+        self.current = if matches { self.current + 1 } else { 0 };
+        self.longest = self.longest.max(self.current);
+    }
+}
+
+impl Trend {
+    /// Summarises `depths`, grouping the deltas into buckets `bucket_width` wide.
+    pub fn new(depths: &[u64], bucket_width: u64) -> Self {
+        let bucket_width = i128::from(bucket_width.max(1));
+
+        let mut rising = Run::default();
+        let mut falling = Run::default();
+        let mut flat = Run::default();
+        let mut largest_jump: Option<Jump> = None;
+        let mut counts = std::collections::BTreeMap::new();
+
+        for (index, pair) in depths.windows(2).enumerate() {
+            let jump = Jump {
+                index,
+                from: pair[0],
+                to: pair[1],
+            };
+
+            rising.step(jump.delta() > 0);
+            falling.step(jump.delta() < 0);
+            flat.step(jump.delta() == 0);
+
+            if largest_jump.is_none_or(|j| jump.delta().abs() > j.delta().abs()) {
+                largest_jump = Some(jump);
+            }
+
+            *counts
+                .entry(jump.delta().div_euclid(bucket_width))
+                .or_insert(0) += 1;
+        }
+
+        let increases = calculate_larger_pairs(depths);
+        let plateaus = depths.windows(2).filter(|v| v[0] == v[1]).count();
+
+        Self {
+            readings: depths.len(),
+            increases,
+            decreases: depths.len().saturating_sub(1) - increases - plateaus,
+            plateaus,
+            longest_increasing_run: rising.longest,
+            longest_decreasing_run: falling.longest,
+            longest_plateau: flat.longest,
+            largest_jump,
+            histogram: counts
+                .into_iter()
+                .map(|(bucket, count)| Bucket {
+                    from: bucket * bucket_width,
+                    to: (bucket + 1) * bucket_width,
+                    count,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("trends are always serializable")
+    }
+}
+
+impl fmt::Display for Trend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = [
+            ("Readings", self.readings),
+            ("Increases", self.increases),
+            ("Decreases", self.decreases),
+            ("Plateaus", self.plateaus),
+            ("Longest increasing run", self.longest_increasing_run),
+            ("Longest decreasing run", self.longest_decreasing_run),
+            ("Longest plateau", self.longest_plateau),
+        ];
+
+        for (name, value) in rows {
+            writeln!(f, "{:<24}{:>10}", name, value)?;
+        }
+
+        if let Some(jump) = self.largest_jump {
+            writeln!(
+                f,
+                "{:<24}{:>+10} ({} -> {} at reading {})",
+                "Largest jump",
+                jump.delta(),
+                jump.from,
+                jump.to,
+                jump.index + 1
+            )?;
+        }
+
+        if !self.histogram.is_empty() {
+            writeln!(f, "\nDeltas")?;
+        }
+
+        for bucket in &self.histogram {
+            let range = format!("[{}, {})", bucket.from, bucket.to);
+            writeln!(f, "{:<24}{:>10}", range, bucket.count)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_trend() {
+        let depths = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+        let trend = Trend::new(&depths, 10);
+
+        assert_eq!(trend.increases, 7);
+        assert_eq!(trend.decreases, 2);
+        assert_eq!(trend.plateaus, 0);
+        assert_eq!(trend.longest_increasing_run, 3);
+        assert_eq!(trend.longest_decreasing_run, 1);
+        assert_eq!(trend.largest_jump.map(|j| j.delta()), Some(33));
+        assert_eq!(trend.histogram.iter().map(|b| b.count).sum::<usize>(), 9);
+        assert_eq!(
+            trend.histogram[0],
+            Bucket {
+                from: -10,
+                to: 0,
+                count: 2
+            }
+        );
+    }
+
+    #[test]
+    fn extreme_depths_do_not_overflow() {
+        let trend = Trend::new(&[0, u64::MAX, 0], u64::MAX);
+        let jump = trend.largest_jump.unwrap();
+
+        assert_eq!((jump.index, jump.delta()), (0, i128::from(u64::MAX)));
+        assert_eq!(trend.longest_decreasing_run, 1);
+        assert_eq!(
+            trend.histogram,
+            [
+                Bucket {
+                    from: -i128::from(u64::MAX),
+                    to: 0,
+                    count: 1
+                },
+                Bucket {
+                    from: i128::from(u64::MAX),
+                    to: 2 * i128::from(u64::MAX),
+                    count: 1
+                }
+            ]
+        );
+        assert!(trend.to_json().contains("18446744073709551615"));
+    }
+
+    #[test]
+    fn plateaus_are_counted_separately() {
+        let trend = Trend::new(&[5, 5, 5, 6, 6], 1);
+
+        assert_eq!(trend.increases, 1);
+        assert_eq!(trend.decreases, 0);
+        assert_eq!(trend.plateaus, 3);
+        assert_eq!(trend.longest_plateau, 2);
+        assert_eq!(Trend::new(&[], 1), Trend::default());
+    }
+}
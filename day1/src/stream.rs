@@ -0,0 +1,108 @@
+use std::collections::VecDeque;
+use std::io::{self, BufRead};
+
+use crate::log::parse_line;
+
+/// Counts increases between depths `window` readings apart as they arrive, only keeping the
+/// last `window` of them.
+///
+/// Comparing sliding sums of `window` depths is the same as comparing the depths at either end,
+/// since everything in between is shared by both sums.
+#[derive(Clone, Debug)]
+pub struct WindowCounter {
+    window: usize,
+    recent: VecDeque<u64>,
+    increases: usize,
+}
+
+impl WindowCounter {
+    pub fn new(window: usize) -> Self {
+        Self {
+            window,
+            recent: VecDeque::with_capacity(window),
+            increases: 0,
+        }
+    }
+
+    pub fn push(&mut self, depth: u64) {
+        if self.window == 0 {
+            return;
+        }
+
+        if self.recent.len() == self.window {
+            let oldest = self.recent.pop_front().unwrap_or(depth);
+
+            if oldest < depth {
+                self.increases += 1;
+            }
+        }
+
+        self.recent.push_back(depth);
+    }
+
+    pub fn increases(&self) -> usize {
+        self.increases
+    }
+}
+
+impl Extend<u64> for WindowCounter {
+    fn extend<I: IntoIterator<Item = u64>>(&mut self, depths: I) {
+        depths.into_iter().for_each(|depth| self.push(depth));
+    }
+}
+
+/// Counts increases over windows of `window` depths read one per line from `reader`, without
+/// holding the whole log in memory.
+///
+/// Blank lines and comments are skipped, and lines that aren't a depth fail with [`io::ErrorKind::InvalidData`].
+pub fn count_increases(mut reader: impl BufRead, window: usize) -> io::Result<usize> {
+    let mut counter = WindowCounter::new(window);
+    let mut line = String::new();
+    let mut number = 0;
+
+    while reader.read_line(&mut line)? > 0 {
+        number += 1;
+
+        if let Some(depth) = parse_line(&line) {
+            let depth = depth.map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("line {}: invalid depth {:?} ({})", number, line.trim(), e),
+                )
+            })?;
+
+            counter.push(depth);
+        }
+
+        line.clear();
+    }
+
+    Ok(counter.increases())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn windows_of_any_size() {
+        assert_eq!(count_increases(EXAMPLE.as_bytes(), 0).unwrap(), 0);
+        assert_eq!(count_increases(EXAMPLE.as_bytes(), 1).unwrap(), 7);
+        assert_eq!(count_increases(EXAMPLE.as_bytes(), 3).unwrap(), 5);
+        assert_eq!(count_increases(EXAMPLE.as_bytes(), 10).unwrap(), 0);
+        assert_eq!(
+            count_increases("# log\r\n1\r\n\r\n2\r\n".as_bytes(), 1).unwrap(),
+            1
+        );
+    }
+
+    #[test]
+    fn invalid_depths_are_reported() {
+        let error = count_increases("199\n200\nabc\n".as_bytes(), 1).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().starts_with("line 3: "));
+    }
+}
@@ -0,0 +1,212 @@
+use aoc_common::{Error, Solution};
+
+fn is_opening_char(c: char) -> bool {
+    matches!(c, '(' | '[' | '{' | '<')
+}
+
+fn get_matching_open_char(c: char) -> Option<char> {
+    match c {
+        ')' => Some('('),
+        ']' => Some('['),
+        '}' => Some('{'),
+        '>' => Some('<'),
+        _ => None,
+    }
+}
+
+fn get_matching_closing_char(c: char) -> Option<char> {
+    match c {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        '<' => Some('>'),
+        _ => None,
+    }
+}
+
+fn invalid_character_to_score(c: char) -> Option<u64> {
+    match c {
+        ')' => Some(3),
+        ']' => Some(57),
+        '}' => Some(1197),
+        '>' => Some(25137),
+        _ => None,
+    }
+}
+
+fn autocomplete_character_to_score(c: char) -> Option<u64> {
+    match c {
+        ')' => Some(1),
+        ']' => Some(2),
+        '}' => Some(3),
+        '>' => Some(4),
+        _ => None,
+    }
+}
+
+fn compute_autocomplete_score(input: &str) -> aoc_common::Result<u64> {
+    input.chars().try_fold(0u64, |acc, x| {
+        let score = autocomplete_character_to_score(x).ok_or(Error::NoSolution(
+            "a completion contains something other than closing characters",
+        ))?;
+
+        acc.checked_mul(5)
+            .and_then(|acc| acc.checked_add(score))
+            .ok_or(Error::NoSolution("an autocomplete score is too large"))
+    })
+}
+
+/// What is wrong with a line of chunks: the first illegal closing character, or the
+/// characters needed to complete it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Status {
+    Corrupted(char),
+    Incomplete(String),
+}
+
+/// Checks one line of the subsystem, where `line` is its 0-indexed position for error reporting.
+pub fn evaluate_line(line: usize, input: &str) -> aoc_common::Result<Status> {
+    let mut stack = Vec::new();
+
+    for (column, c) in input.chars().enumerate() {
+        if is_opening_char(c) {
+            stack.push(c);
+        } else {
+            let opening_char = get_matching_open_char(c).ok_or(Error::InvalidCharacter {
+                line: line + 1,
+                column: column + 1,
+                character: c,
+            })?;
+
+            // Get the top of the stack
+            let top = stack.pop();
+
+            // If it's none or the wrong character, the line is corrupted
+            if top.map(|t| t != opening_char).unwrap_or_default() {
+                return Ok(Status::Corrupted(c));
+            }
+        }
+    }
+
+    let completion_string = stack
+        .into_iter()
+        .rev()
+        .filter_map(get_matching_closing_char)
+        .collect();
+
+    Ok(Status::Incomplete(completion_string))
+}
+
+#[derive(Debug)]
+pub struct NavigationSubsystem {
+    lines: Vec<Status>,
+}
+
+impl NavigationSubsystem {
+    pub fn new(lines: Vec<Status>) -> Self {
+        Self { lines }
+    }
+
+    pub fn lines(&self) -> &[Status] {
+        &self.lines
+    }
+}
+
+impl Solution for NavigationSubsystem {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn from_input(input: &str) -> aoc_common::Result<Self> {
+        let lines = aoc_common::non_empty(input)?
+            .lines()
+            .enumerate()
+            // A blank line would otherwise count as complete, with a score of 0
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| evaluate_line(i, line))
+            .collect::<aoc_common::Result<_>>()?;
+
+        Ok(Self { lines })
+    }
+
+    fn part1(&self) -> aoc_common::Result<u64> {
+        self.lines
+            .iter()
+            .filter_map(|status| match status {
+                Status::Corrupted(c) => {
+                    Some(invalid_character_to_score(*c).ok_or(Error::NoSolution(
+                        "a line is corrupted by something other than a closing character",
+                    )))
+                }
+                Status::Incomplete(_) => None,
+            })
+            .sum()
+    }
+
+    fn part2(&self) -> aoc_common::Result<u64> {
+        // Score each string
+        let mut scores = self
+            .lines
+            .iter()
+            .filter_map(|status| match status {
+                Status::Corrupted(_) => None,
+                Status::Incomplete(completion) => Some(compute_autocomplete_score(completion)),
+            })
+            .collect::<aoc_common::Result<Vec<_>>>()?;
+
+        // Sort the values and get the middle one
+        scores.sort_unstable();
+
+        scores
+            .get(scores.len() / 2)
+            .copied()
+            .ok_or(Error::NoSolution("every line is corrupted"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let subsystem = NavigationSubsystem::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(subsystem.part1().unwrap(), 26397);
+        assert_eq!(subsystem.part2().unwrap(), 288957);
+    }
+
+    #[test]
+    fn blank_lines_are_ignored() {
+        let subsystem = NavigationSubsystem::from_input(&format!("{}\n\n\n", EXAMPLE)).unwrap();
+
+        assert_eq!(subsystem.lines().len(), 10);
+        assert_eq!(subsystem.part2().unwrap(), 288957);
+    }
+
+    #[test]
+    fn large_autocomplete_scores_are_reported() {
+        let subsystem = NavigationSubsystem::from_input(&"(".repeat(30)).unwrap();
+
+        assert!(matches!(subsystem.part2(), Err(Error::NoSolution(_))));
+        assert_eq!(
+            compute_autocomplete_score(&")".repeat(27)).unwrap(),
+            1862645149230957031
+        );
+    }
+
+    #[test]
+    fn invalid_statuses_are_reported() {
+        let corrupted = NavigationSubsystem::new(vec![Status::Corrupted('x')]);
+        let incomplete = NavigationSubsystem::new(vec![Status::Incomplete(String::from("ab"))]);
+
+        assert!(matches!(corrupted.part1(), Err(Error::NoSolution(_))));
+        assert!(matches!(incomplete.part2(), Err(Error::NoSolution(_))));
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<NavigationSubsystem>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,323 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use nom::{
+    branch::alt,
+    combinator::map,
+    sequence::{preceded, separated_pair},
+};
+
+use aoc_common::grid::{Coord, Grid};
+use aoc_common::parse::{self, tag, IResult};
+use aoc_common::point::{Axis, Point};
+use aoc_common::render::{Image, Render, Rgb};
+use aoc_common::{Error, Result, Solution};
+
+/// The most cells a paper may cover before it's too large to draw.
+const MAX_CELLS: usize = 10_000_000;
+
+fn axis(input: &str) -> IResult<&str, Axis> {
+    alt((map(tag("x"), |_| Axis::X), map(tag("y"), |_| Axis::Y)))(input)
+}
+
+/// The transparent paper, as the set of positions marked with a dot.
+#[derive(Clone, Debug)]
+pub struct Paper {
+    dots: HashSet<Point>,
+}
+
+impl Paper {
+    pub fn new(dots: HashSet<Point>) -> Self {
+        Self { dots }
+    }
+
+    pub fn dots(&self) -> &HashSet<Point> {
+        &self.dots
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(parse::lines(Point::parse), |dots| Self {
+            dots: dots.into_iter().collect(),
+        })(input)
+    }
+}
+
+/// A fold along a line of the paper, such as `fold along y=7`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Instruction {
+    pub axis: Axis,
+    pub position: i32,
+}
+
+impl Instruction {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            preceded(
+                tag("fold along "),
+                separated_pair(axis, tag("="), parse::i32),
+            ),
+            |(axis, position)| Self { axis, position },
+        )(input)
+    }
+
+    /// Where `dot` ends up after the fold, moving it only if it is on the folded over half.
+    /// Returns `None` if it would end up out of range.
+    pub fn apply(&self, dot: Point) -> Option<Point> {
+        match dot.get(self.axis) < self.position {
+            true => Some(dot),
+            false => dot.reflect(self.axis, self.position),
+        }
+    }
+
+    pub fn fold(&self, paper: &Paper) -> Result<Paper> {
+        let dots = paper
+            .dots
+            .iter()
+            .map(|d| self.apply(*d))
+            .collect::<Option<_>>()
+            .ok_or(Error::NoSolution("a fold moves a dot out of range"))?;
+
+        Ok(Paper { dots })
+    }
+}
+
+impl Paper {
+    /// Positions of the dots as grid coordinates.
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + '_ {
+        // Dots can't end up at negative positions, so those are left off the picture
+        self.dots
+            .iter()
+            .filter_map(|dot| Coord::try_from(*dot).ok())
+    }
+
+    /// The width and height of a grid just big enough to hold all of the dots.
+    pub fn size(&self) -> (usize, usize) {
+        let width = self.coords().map(|c| c.x + 1).max().unwrap_or_default();
+        let height = self.coords().map(|c| c.y + 1).max().unwrap_or_default();
+
+        (width, height)
+    }
+
+    /// The paper's size, failing if it is too large to draw.
+    pub fn drawable_size(&self) -> Result<(usize, usize)> {
+        let (width, height) = self.size();
+
+        match width.checked_mul(height) {
+            Some(cells) if cells <= MAX_CELLS => Ok((width, height)),
+            _ => Err(Error::NoSolution("the paper is too large to draw")),
+        }
+    }
+
+    /// Lays the dots out on a grid just big enough to hold all of them.
+    pub fn to_grid(&self) -> Result<Grid<char>> {
+        let (width, height) = self.drawable_size()?;
+
+        Ok(self.draw(width, height))
+    }
+
+    /// Lays the dots out on a grid of a fixed size, leaving off any that don't fit.
+    pub fn draw(&self, width: usize, height: usize) -> Grid<char> {
+        let mut grid = Grid::filled(width, height, '.');
+
+        for coord in self.coords() {
+            if let Some(cell) = grid.get_mut(coord) {
+                *cell = '#';
+            }
+        }
+
+        grid
+    }
+}
+
+impl fmt::Display for Paper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Written a cell at a time rather than through a grid, so it can't run out of memory
+        let (width, height) = self.size();
+        let coords: HashSet<_> = self.coords().collect();
+
+        for y in 0..height {
+            for x in 0..width {
+                match coords.contains(&Coord::new(x, y)) {
+                    true => write!(f, "# ")?,
+                    false => write!(f, ". ")?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The paper as it starts out and the folds to make in it.
+#[derive(Debug)]
+pub struct Input {
+    paper: Paper,
+    instructions: Vec<Instruction>,
+}
+
+impl Input {
+    pub fn new(paper: Paper, instructions: Vec<Instruction>) -> Self {
+        Self {
+            paper,
+            instructions,
+        }
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            separated_pair(
+                Paper::parse,
+                parse::blank_line,
+                parse::lines(Instruction::parse),
+            ),
+            |(paper, instructions)| Self {
+                paper,
+                instructions,
+            },
+        )(input)
+    }
+
+    pub fn paper(&self) -> &Paper {
+        &self.paper
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    /// The paper after only the first fold, failing if there are none.
+    pub fn fold_first(&self) -> Result<Paper> {
+        self.instructions
+            .first()
+            .ok_or(Error::NoSolution("there are no folds to make"))?
+            .fold(&self.paper)
+    }
+
+    pub fn fold_all(&self) -> Result<Paper> {
+        self.instructions
+            .iter()
+            .try_fold(self.paper.clone(), |p, i| i.fold(&p))
+    }
+
+    /// The paper before any folds and then after each of them.
+    pub fn folds(&self) -> Result<Vec<Paper>> {
+        let mut papers = vec![self.paper.clone()];
+
+        for instruction in &self.instructions {
+            let next = instruction.fold(&papers[papers.len() - 1])?;
+            papers.push(next);
+        }
+
+        Ok(papers)
+    }
+}
+
+impl Render for Input {
+    fn frames(&self) -> Vec<Image> {
+        let Ok(papers) = self.folds() else {
+            return Vec::new();
+        };
+
+        // Keep every frame the size of the unfolded paper so they can be played back
+        let Ok((width, height)) = papers[0].drawable_size() else {
+            return Vec::new();
+        };
+
+        papers
+            .iter()
+            .map(|paper| {
+                Image::from_grid(&paper.draw(width, height), 2, |c| match c {
+                    '#' => Rgb::WHITE,
+                    _ => Rgb::BLACK,
+                })
+            })
+            .collect()
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = Paper;
+
+    fn from_input(input: &str) -> Result<Self> {
+        let input = parse::finish(input, Self::parse)?;
+
+        Ok(input)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.fold_first()?.dots.len())
+    }
+
+    fn part2(&self) -> Result<Paper> {
+        let paper = self.fold_all()?;
+        paper.drawable_size()?;
+
+        Ok(paper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let input = Input::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(input.part1().unwrap(), 17);
+
+        let expected = "\
+# # # # # 
+# . . . # 
+# . . . # 
+# . . . # 
+# # # # # 
+";
+
+        assert_eq!(input.part2().unwrap().to_string(), expected);
+        assert_eq!(input.frames().len(), 3);
+    }
+
+    #[test]
+    fn folds_out_of_range_are_reported() {
+        let input = Input::from_input("1600000000,1\n\nfold along x=1500000000\n").unwrap();
+
+        assert!(matches!(input.part1(), Err(Error::NoSolution(_))));
+        assert!(input.frames().is_empty());
+    }
+
+    #[test]
+    fn papers_too_large_are_reported() {
+        let input = Input::from_input("100000,100000\n\nfold along y=1000000\n").unwrap();
+
+        assert!(matches!(input.part2(), Err(Error::NoSolution(_))));
+        assert!(input.frames().is_empty());
+        assert_eq!(input.paper().size(), (100001, 100001));
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<Input>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    proptest! {
+        #[test]
+        fn folding_never_adds_dots(
+            dots in prop::collection::hash_set((0..50, 0..50).prop_map(|(x, y)| Point::new(x, y)), 0..100),
+            axis in prop_oneof![Just(Axis::X), Just(Axis::Y)],
+            position in 0..50,
+        ) {
+            let paper = Paper { dots };
+            let folded = Instruction { axis, position }.fold(&paper).unwrap();
+
+            prop_assert!(folded.dots.len() <= paper.dots.len());
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use nom::branch::alt;
+use nom::character::complete::space1;
+use nom::sequence::separated_pair;
+
+use aoc_common::parse::{self, tag, IResult};
+use aoc_common::{Error, Result, Solution};
+
+pub mod program;
+
+use program::{Program, Statement};
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Direction {
+    Forward,
+    Up,
+    Down,
+    Back,
+    /// Goes straight to the depth given as the magnitude.
+    DiveTo,
+}
+
+impl Direction {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (input, slice) = alt((
+            tag("forward"),
+            tag("up"),
+            tag("down"),
+            tag("back"),
+            tag("dive-to"),
+        ))(input)?;
+
+        let variant = match slice {
+            "forward" => Self::Forward,
+            "up" => Self::Up,
+            "down" => Self::Down,
+            "back" => Self::Back,
+            "dive-to" => Self::DiveTo,
+            _ => unreachable!(),
+        };
+
+        Ok((input, variant))
+    }
+}
+
+/// A single line of the planned course, such as `forward 5`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Command {
+    pub direction: Direction,
+    pub magnitude: i64,
+}
+
+impl Command {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (input, (direction, magnitude)) =
+            separated_pair(Direction::parse, space1, parse::i64)(input)?;
+
+        Ok((
+            input,
+            Command {
+                direction,
+                magnitude,
+            },
+        ))
+    }
+}
+
+const TOO_FAR: Error = Error::NoSolution("the submarine travels too far");
+
+/// One way of interpreting the planned course, fed the commands one at a time.
+pub trait Navigator {
+    fn name(&self) -> &'static str;
+
+    fn navigate(&mut self, command: Command) -> Result<()>;
+
+    /// The puzzle answer for wherever the submarine has ended up.
+    fn compute_answer(&self) -> Result<i64>;
+}
+
+/// The models of how the submarine moves, in puzzle part order.
+pub fn navigators() -> Vec<Box<dyn Navigator>> {
+    vec![
+        Box::new(State::default()),
+        Box::new(StateWithAim::default()),
+    ]
+}
+
+/// Where the submarine is when commands move it directly, as in part 1.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct State {
+    pub position: i64,
+    pub depth: i64,
+}
+
+impl State {
+    pub fn new(position: i64, depth: i64) -> Self {
+        Self { position, depth }
+    }
+
+    /// Where the submarine ends up after the command, or `None` if that is out of range.
+    pub fn apply(self, command: Command) -> Option<Self> {
+        let Command {
+            direction,
+            magnitude,
+        } = command;
+
+        Some(match direction {
+            Direction::Forward => Self::new(self.position.checked_add(magnitude)?, self.depth),
+            Direction::Up => Self::new(self.position, self.depth.checked_sub(magnitude)?),
+            Direction::Down => Self::new(self.position, self.depth.checked_add(magnitude)?),
+            Direction::Back => Self::new(self.position.checked_sub(magnitude)?, self.depth),
+            Direction::DiveTo => Self::new(self.position, magnitude),
+        })
+    }
+}
+
+impl Navigator for State {
+    fn name(&self) -> &'static str {
+        "direct"
+    }
+
+    fn navigate(&mut self, command: Command) -> Result<()> {
+        *self = self.apply(command).ok_or(TOO_FAR)?;
+
+        Ok(())
+    }
+
+    /// The horizontal position multiplied by the depth.
+    fn compute_answer(&self) -> Result<i64> {
+        self.position.checked_mul(self.depth).ok_or(TOO_FAR)
+    }
+}
+
+/// Where the submarine is when `up` and `down` change its aim instead, as in part 2.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct StateWithAim {
+    pub state: State,
+    pub aim: i64,
+}
+
+impl StateWithAim {
+    pub fn new(position: i64, depth: i64, aim: i64) -> Self {
+        Self {
+            state: State::new(position, depth),
+            aim,
+        }
+    }
+
+    /// Where the submarine ends up after the command, or `None` if that is out of range.
+    pub fn apply(self, command: Command) -> Option<Self> {
+        let Command {
+            direction,
+            magnitude,
+        } = command;
+        let State { position, depth } = self.state;
+
+        Some(match direction {
+            Direction::Forward => Self::new(
+                position.checked_add(magnitude)?,
+                depth.checked_add(self.aim.checked_mul(magnitude)?)?,
+                self.aim,
+            ),
+            Direction::Up => Self::new(position, depth, self.aim.checked_sub(magnitude)?),
+            Direction::Down => Self::new(position, depth, self.aim.checked_add(magnitude)?),
+            // Reverses along the current aim, undoing a `forward` of the same distance
+            Direction::Back => Self::new(
+                position.checked_sub(magnitude)?,
+                depth.checked_sub(self.aim.checked_mul(magnitude)?)?,
+                self.aim,
+            ),
+            Direction::DiveTo => Self::new(position, magnitude, self.aim),
+        })
+    }
+}
+
+impl Navigator for StateWithAim {
+    fn name(&self) -> &'static str {
+        "with aim"
+    }
+
+    fn navigate(&mut self, command: Command) -> Result<()> {
+        *self = self.apply(command).ok_or(TOO_FAR)?;
+
+        Ok(())
+    }
+
+    fn compute_answer(&self) -> Result<i64> {
+        self.state.compute_answer()
+    }
+}
+
+/// The planned course, written in the command language described in [`program`].
+#[derive(Debug)]
+pub struct Course {
+    program: Program,
+}
+
+impl Course {
+    pub fn new(commands: Vec<Command>) -> Self {
+        let statements = commands.into_iter().map(Statement::Command).collect();
+
+        Self {
+            program: Program::new(statements),
+        }
+    }
+
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// Every command in the order it is carried out, with repeats expanded.
+    pub fn commands(&self) -> impl Iterator<Item = Command> + '_ {
+        self.program.commands()
+    }
+
+    pub fn navigate<N: Navigator>(&self, mut navigator: N) -> Result<N> {
+        for command in self.commands() {
+            navigator.navigate(command)?;
+        }
+
+        Ok(navigator)
+    }
+
+    /// Steers every navigator through the course together, in a single pass over the commands.
+    pub fn navigate_all(&self, navigators: &mut [Box<dyn Navigator>]) -> Result<()> {
+        for command in self.commands() {
+            for navigator in navigators.iter_mut() {
+                navigator.navigate(command)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Solution for Course {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn from_input(input: &str) -> Result<Self> {
+        let program = parse::finish(input, Program::parse)?;
+
+        Ok(Self { program })
+    }
+
+    fn part1(&self) -> Result<i64> {
+        self.navigate(State::default())?.compute_answer()
+    }
+
+    fn part2(&self) -> Result<i64> {
+        self.navigate(StateWithAim::default())?.compute_answer()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let course = Course::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(course.part1().unwrap(), 150);
+        assert_eq!(course.part2().unwrap(), 900);
+    }
+
+    /// Only counts how far the submarine has been driven, in either direction.
+    #[derive(Default)]
+    struct Odometer(i64);
+
+    impl Navigator for Odometer {
+        fn name(&self) -> &'static str {
+            "odometer"
+        }
+
+        fn navigate(&mut self, command: Command) -> Result<()> {
+            if matches!(command.direction, Direction::Forward | Direction::Back) {
+                self.0 += command.magnitude;
+            }
+
+            Ok(())
+        }
+
+        fn compute_answer(&self) -> Result<i64> {
+            Ok(self.0)
+        }
+    }
+
+    #[test]
+    fn navigators_share_one_pass() {
+        let course = Course::from_input(EXAMPLE).unwrap();
+        let mut navigators = navigators();
+        navigators.push(Box::new(Odometer::default()));
+
+        course.navigate_all(&mut navigators).unwrap();
+
+        let answers: Vec<_> = navigators
+            .iter()
+            .map(|n| (n.name(), n.compute_answer().unwrap()))
+            .collect();
+
+        assert_eq!(
+            answers,
+            [("direct", 150), ("with aim", 900), ("odometer", 15)]
+        );
+    }
+
+    #[test]
+    fn overflow_is_reported() {
+        let far = Course::from_input("forward 9223372036854775807\nforward 1\n").unwrap();
+        let deep = Course::from_input("forward 2\ndown 9223372036854775807\n").unwrap();
+
+        assert!(matches!(far.part1(), Err(Error::NoSolution(_))));
+        assert!(matches!(far.part2(), Err(Error::NoSolution(_))));
+        assert!(matches!(deep.part1(), Err(Error::NoSolution(_))));
+        assert_eq!(deep.part2().unwrap(), 0);
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<Course>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
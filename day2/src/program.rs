@@ -0,0 +1,288 @@
+//! The submarine's command language.
+//!
+//! A program has one statement per line: either a [`Command`] such as `forward 5` or
+//! `dive-to 20`, or a block repeating the statements inside it:
+//!
+//! ```text
+//! # Zig-zag down the trench
+//! repeat 3 {
+//!     forward 5
+//!     down 2   # steeper each time
+//! }
+//! ```
+//!
+//! Blank lines and comments starting with `#` are ignored, and the last line doesn't need to
+//! end with a newline. Repeats can be nested up to [`MAX_DEPTH`] deep, and a program may expand
+//! to at most [`MAX_COMMANDS`] commands.
+
+use nom::{
+    branch::alt,
+    character::complete::{line_ending, not_line_ending, space0, space1},
+    combinator::{cut, eof, fail, map, opt, recognize, value, verify},
+    error::context,
+    sequence::{delimited, pair, preceded, terminated, tuple},
+};
+
+use aoc_common::parse::{self, tag, IResult};
+
+use crate::Command;
+
+/// How deeply repeats can be nested inside each other.
+pub const MAX_DEPTH: usize = 100;
+
+/// How many commands a program can expand to once its repeats are carried out.
+pub const MAX_COMMANDS: u64 = 10_000_000;
+
+/// Fails at `input` without trying any alternatives, reporting what was `expected` there.
+fn reject<'a, O>(input: &'a str, expected: &'static str) -> IResult<&'a str, O> {
+    cut(context(expected, fail))(input)
+}
+
+fn comment(input: &str) -> IResult<&str, &str> {
+    recognize(pair(tag("#"), not_line_ending))(input)
+}
+
+/// Whatever follows a statement on its line: an optional comment, then the line break.
+fn end_of_line(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        tuple((
+            space0,
+            opt(comment),
+            context("the end of the line", alt((line_ending, eof))),
+        )),
+    )(input)
+}
+
+/// A line with nothing to run on it.
+fn empty_line(input: &str) -> IResult<&str, &str> {
+    verify(recognize(end_of_line), |line: &str| !line.is_empty())(input)
+}
+
+/// Statements up to the end of the input or the `}` closing the enclosing block, which is
+/// nested `depth` repeats deep.
+fn statements(mut input: &str, depth: usize) -> IResult<&str, Vec<Statement>> {
+    let mut statements = Vec::new();
+    let mut commands = 0u64;
+
+    loop {
+        while let Ok((rest, _)) = empty_line(input) {
+            input = rest;
+        }
+
+        if input.is_empty() || preceded(space0, tag("}"))(input).is_ok() {
+            return Ok((input, statements));
+        }
+
+        let start = space0(input)?.0;
+        let (rest, statement) =
+            terminated(|input| Statement::parse_nested(input, depth), end_of_line)(start)?;
+
+        commands = commands.saturating_add(statement.command_count());
+
+        if commands > MAX_COMMANDS {
+            return reject(start, "a program of at most 10000000 commands");
+        }
+
+        statements.push(statement);
+        input = rest;
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Statement {
+    Command(Command),
+    Repeat { times: u32, body: Vec<Statement> },
+}
+
+impl Statement {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        Self::parse_nested(input, 0)
+    }
+
+    /// A statement inside `depth` repeats.
+    fn parse_nested(input: &str, depth: usize) -> IResult<&str, Self> {
+        alt((
+            |input| Self::repeat(input, depth),
+            map(Command::parse, Self::Command),
+        ))(input)
+    }
+
+    fn repeat(start: &str, depth: usize) -> IResult<&str, Self> {
+        let (input, times) = delimited(
+            pair(tag("repeat"), space1),
+            parse::u32,
+            pair(space0, tag("{")),
+        )(start)?;
+
+        if depth >= MAX_DEPTH {
+            return reject(start, "at most 100 nested repeats");
+        }
+
+        let (input, _) = end_of_line(input)?;
+        let (input, body) = statements(input, depth + 1)?;
+        let (input, _) = preceded(space0, tag("}"))(input)?;
+
+        Ok((input, Self::Repeat { times, body }))
+    }
+
+    /// How many commands the statement expands to, saturating rather than overflowing.
+    pub fn command_count(&self) -> u64 {
+        match self {
+            Self::Command(_) => 1,
+            Self::Repeat { times, body } => {
+                let body = body
+                    .iter()
+                    .map(Self::command_count)
+                    .fold(0, u64::saturating_add);
+
+                u64::from(*times).saturating_mul(body)
+            }
+        }
+    }
+
+    /// The commands this statement carries out, generated as they're needed so that large
+    /// repeats don't have to be held in memory.
+    pub fn commands(&self) -> Box<dyn Iterator<Item = Command> + '_> {
+        match self {
+            Self::Command(command) => Box::new(std::iter::once(*command)),
+            Self::Repeat { times, body } => {
+                Box::new((0..*times).flat_map(move |_| body.iter().flat_map(Statement::commands)))
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Program {
+    statements: Vec<Statement>,
+}
+
+impl Program {
+    pub fn new(statements: Vec<Statement>) -> Self {
+        Self { statements }
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(|input| statements(input, 0), Self::new)(input)
+    }
+
+    pub fn statements(&self) -> &[Statement] {
+        &self.statements
+    }
+
+    pub fn commands(&self) -> impl Iterator<Item = Command> + '_ {
+        self.statements.iter().flat_map(Statement::commands)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse;
+
+    use super::*;
+    use crate::{Direction, State, StateWithAim};
+
+    const PROGRAM: &str = "\
+# Zig-zag down the trench\r
+repeat 3 {\r
+    forward  5\r
+\r
+    repeat 2 {\r
+        down 1 # steeper each time\r
+    }\r
+}\r
+back 5\r
+dive-to 4";
+
+    #[test]
+    fn programs_are_expanded() {
+        let program = parse::finish(PROGRAM, Program::parse).unwrap();
+        let commands: Vec<_> = program.commands().collect();
+
+        assert_eq!(program.statements().len(), 3);
+        assert_eq!(commands.len(), 11);
+        assert_eq!(
+            commands[10],
+            Command {
+                direction: Direction::DiveTo,
+                magnitude: 4
+            }
+        );
+
+        let state = program.commands().try_fold(State::default(), State::apply);
+        let aimed = program
+            .commands()
+            .try_fold(StateWithAim::default(), StateWithAim::apply);
+
+        assert_eq!(state, Some(State::new(10, 4)));
+        assert_eq!(aimed, Some(StateWithAim::new(10, 4, 6)));
+    }
+
+    #[test]
+    fn errors_have_line_numbers() {
+        let message = |input| {
+            parse::finish(input, Program::parse)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            message("forward 5\n\nsideways 2\n"),
+            "line 3, column 1: expected one of `repeat`, `forward`, `up`, `down`, `back`, `dive-to`, found `s`"
+        );
+        assert_eq!(
+            message("repeat 2 {\n  up 1\n"),
+            "line 3, column 1: expected `}`, found the end of the input"
+        );
+        assert_eq!(
+            message("down 2 3\n"),
+            "line 1, column 8: expected the end of the line, found `3`"
+        );
+        assert_eq!(
+            message("up 1\n}\n"),
+            "line 2, column 1: unexpected trailing input `}`"
+        );
+    }
+
+    #[test]
+    fn deep_nesting_is_rejected() {
+        let nested = |depth| {
+            let mut program = "repeat 1 {\n".repeat(depth);
+            program.push_str("forward 1\n");
+            program.push_str(&"}\n".repeat(depth));
+            program
+        };
+
+        let program = parse::finish(&nested(MAX_DEPTH), Program::parse).unwrap();
+        assert_eq!(program.commands().count(), 1);
+
+        assert_eq!(
+            parse::finish(&nested(3000), Program::parse)
+                .unwrap_err()
+                .to_string(),
+            "line 101, column 1: expected at most 100 nested repeats, found `r`"
+        );
+    }
+
+    #[test]
+    fn huge_expansions_are_rejected() {
+        let message = |input| {
+            parse::finish(input, Program::parse)
+                .unwrap_err()
+                .to_string()
+        };
+
+        assert_eq!(
+            message("repeat 4294967295 {\n  repeat 4294967295 {\n    forward 1\n  }\n}\n"),
+            "line 2, column 3: expected a program of at most 10000000 commands, found `r`"
+        );
+        assert_eq!(
+            message("repeat 6000000 {\n  up 1\n}\nrepeat 6000000 {\n  down 1\n}\n"),
+            "line 4, column 1: expected a program of at most 10000000 commands, found `r`"
+        );
+
+        let program = parse::finish("repeat 10000000 {\n  up 1\n}\n", Program::parse).unwrap();
+        assert_eq!(program.statements()[0].command_count(), MAX_COMMANDS);
+    }
+}
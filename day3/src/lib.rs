@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+
+use aoc_common::{Error, Result, Solution};
+
+#[derive(Copy, Clone, Debug, Default)]
+struct IndexCount {
+    zeros: usize,
+    ones: usize,
+}
+
+impl IndexCount {
+    fn update(&mut self, value: char) {
+        match value {
+            '0' => self.zeros += 1,
+            _ => self.ones += 1,
+        }
+    }
+
+    fn resolve(self) -> usize {
+        usize::from(self.zeros < self.ones)
+    }
+}
+
+/// Which bit to keep at each position when finding the life support ratings.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum BitCriteria {
+    MostCommon,
+    LeastCommon,
+}
+
+fn resolve_most_common(zeros: usize, ones: usize) -> char {
+    match zeros.cmp(&ones) {
+        std::cmp::Ordering::Greater => '0',
+        _ => '1',
+    }
+}
+
+fn resolve_least_common(zeros: usize, ones: usize) -> char {
+    match ones.cmp(&zeros) {
+        std::cmp::Ordering::Less => '1',
+        _ => '0',
+    }
+}
+
+impl BitCriteria {
+    fn resolve(&self, zeros: usize, ones: usize) -> char {
+        match *self {
+            Self::MostCommon => resolve_most_common(zeros, ones),
+            Self::LeastCommon => resolve_least_common(zeros, ones),
+        }
+    }
+}
+
+/// Narrows the values down bit by bit using `criteria` until one is left, returning it as a number.
+fn filter(values: Vec<&str>, criteria: BitCriteria) -> usize {
+    usize::from_str_radix(
+        (0..values[0].len()).fold(values, |v, i| filter_by_index(v, criteria, i))[0],
+        2,
+    )
+    .unwrap()
+}
+
+fn filter_by_index(values: Vec<&str>, criteria: BitCriteria, index: usize) -> Vec<&str> {
+    let mut counts: HashMap<char, usize> = HashMap::new();
+
+    for v in &values {
+        let c = v.chars().nth(index).unwrap();
+        *counts.entry(c).or_default() += 1;
+    }
+
+    let zeros = counts.get(&'0').copied().unwrap_or_default();
+    let ones = counts.get(&'1').copied().unwrap_or_default();
+
+    let bit = criteria.resolve(zeros, ones);
+
+    let filtered: Vec<_> = values
+        .iter()
+        .filter(|v| v.chars().nth(index).unwrap() == bit)
+        .copied()
+        .collect();
+
+    match filtered.len() {
+        0 => values,
+        _ => filtered,
+    }
+}
+
+/// The diagnostic report, as binary numbers of equal width.
+#[derive(Debug)]
+pub struct Diagnostic {
+    values: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn values(&self) -> Vec<&str> {
+        self.values.iter().map(String::as_str).collect()
+    }
+
+    pub fn oxygen_generator_rating(&self) -> usize {
+        filter(self.values(), BitCriteria::MostCommon)
+    }
+
+    pub fn co2_scrubber_rating(&self) -> usize {
+        filter(self.values(), BitCriteria::LeastCommon)
+    }
+}
+
+impl Solution for Diagnostic {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn from_input(input: &str) -> Result<Self> {
+        let values: Vec<_> = aoc_common::non_empty(input)?
+            .trim_end()
+            .lines()
+            .map(String::from)
+            .collect();
+
+        let width = values[0].len();
+
+        // Ratings are read as numbers, which need a spare bit for the epsilon rate's mask
+        if width >= usize::BITS as usize {
+            return Err(Error::Parse {
+                line: 1,
+                column: 1,
+                message: format!(
+                    "expected at most {} bits but found {}",
+                    usize::BITS - 1,
+                    width
+                ),
+            });
+        }
+
+        for (line, value) in values.iter().enumerate() {
+            if let Some((column, character)) = value
+                .chars()
+                .enumerate()
+                .find(|(_, c)| !matches!(c, '0' | '1'))
+            {
+                return Err(Error::InvalidCharacter {
+                    line: line + 1,
+                    column: column + 1,
+                    character,
+                });
+            }
+
+            if value.len() != width {
+                return Err(Error::Parse {
+                    line: line + 1,
+                    column: 1,
+                    message: format!("expected {} bits but found {}", width, value.len()),
+                });
+            }
+        }
+
+        Ok(Self { values })
+    }
+
+    fn part1(&self) -> Result<usize> {
+        // Find the most common in each position
+        let mut counts: HashMap<usize, IndexCount> = HashMap::new();
+
+        self.values.iter().for_each(|v| {
+            // Update the mapping counts
+            for (i, c) in v.chars().enumerate() {
+                counts.entry(i).or_default().update(c);
+            }
+        });
+
+        let width = self.values[0].len();
+
+        let gamma: usize = (0..width)
+            .map(|i| counts[&i].resolve() << (width - i - 1))
+            .sum();
+
+        let epsilon = (1 << width) - gamma - 1;
+
+        gamma
+            .checked_mul(epsilon)
+            .ok_or(Error::NoSolution("the power consumption is too large"))
+    }
+
+    fn part2(&self) -> Result<usize> {
+        self.oxygen_generator_rating()
+            .checked_mul(self.co2_scrubber_rating())
+            .ok_or(Error::NoSolution("the life support rating is too large"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let diagnostic = Diagnostic::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(diagnostic.part1().unwrap(), 198);
+        assert_eq!(diagnostic.part2().unwrap(), 230);
+    }
+
+    #[test]
+    fn trailing_blank_lines_are_ignored() {
+        let diagnostic = Diagnostic::from_input(&format!("{}\n\n", EXAMPLE)).unwrap();
+
+        assert_eq!(diagnostic.part1().unwrap(), 198);
+    }
+
+    #[test]
+    fn wide_rows_are_rejected() {
+        let row = |bit: &str| bit.repeat(usize::BITS as usize);
+        let input = format!("{}\n{}\n", row("0"), row("1"));
+
+        assert!(matches!(
+            Diagnostic::from_input(&input),
+            Err(Error::Parse { line: 1, .. })
+        ));
+
+        // Wide enough to read, but both rates are too large to multiply
+        let diagnostic = Diagnostic::from_input(&"10".repeat(32)[1..]).unwrap();
+
+        assert!(matches!(diagnostic.part1(), Err(Error::NoSolution(_))));
+        assert!(matches!(diagnostic.part2(), Err(Error::NoSolution(_))));
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<Diagnostic>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
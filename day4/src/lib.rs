@@ -0,0 +1,274 @@
+use nom::{
+    character::complete::space0,
+    combinator::map_opt,
+    error::context,
+    multi::many1,
+    sequence::{delimited, separated_pair},
+};
+
+use aoc_common::grid::{Coord, Grid};
+use aoc_common::parse::{self, IResult};
+use aoc_common::render::{Image, Render, Rgb};
+use aoc_common::{Error, Result, Solution};
+
+/// Whether a number on a board has been called yet.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum State {
+    Marked,
+    Unmarked,
+}
+
+#[derive(Clone, Debug)]
+pub struct GridValue {
+    pub value: u32,
+    pub state: State,
+}
+
+impl GridValue {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (input, value) = delimited(space0, parse::u32, space0)(input)?;
+
+        Ok((
+            input,
+            Self {
+                value,
+                state: State::Unmarked,
+            },
+        ))
+    }
+
+    pub fn mark(&mut self, value: u32) {
+        if self.value == value {
+            self.state = State::Marked;
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Board {
+    values: Grid<GridValue>,
+}
+
+impl Board {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let rows = parse::lines(many1(GridValue::parse));
+        let (input, values) = context("a bingo board", map_opt(rows, Grid::from_rows))(input)?;
+
+        Ok((input, Self { values }))
+    }
+
+    pub fn values(&self) -> &Grid<GridValue> {
+        &self.values
+    }
+
+    pub fn is_complete(&self) -> bool {
+        let is_marked = |v: &GridValue| v.state == State::Marked;
+
+        self.values.rows().any(|row| row.iter().all(is_marked))
+            || self
+                .values
+                .columns()
+                .any(|mut column| column.all(is_marked))
+    }
+
+    /// Sums the numbers that haven't been called, or `None` if it doesn't fit in a `u32`.
+    pub fn unmarked_sum(&self) -> Option<u32> {
+        self.values
+            .values()
+            .filter(|v| v.state == State::Unmarked)
+            .try_fold(0u32, |sum, v| sum.checked_add(v.value))
+    }
+
+    /// The board's score once `value` has completed it, or `None` if it doesn't fit in a `u32`.
+    pub fn score(&self, value: u32) -> Option<u32> {
+        value.checked_mul(self.unmarked_sum()?)
+    }
+
+    /// Marks `value` if it is on the board, returning whether that completes it.
+    pub fn mark(&mut self, value: u32) -> bool {
+        // Find the value and mark it
+        self.values.values_mut().for_each(|v| v.mark(value));
+
+        self.is_complete()
+    }
+}
+
+/// The numbers to be called and the boards being played.
+#[derive(Clone, Debug)]
+pub struct BingoFile {
+    order: Vec<u32>,
+    grids: Vec<Board>,
+}
+
+impl BingoFile {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (input, (order, grids)) = separated_pair(
+            parse::comma_separated(parse::u32),
+            parse::blank_line,
+            parse::blocks(Board::parse),
+        )(input)?;
+
+        Ok((input, Self { order, grids }))
+    }
+
+    pub fn order(&self) -> &[u32] {
+        &self.order
+    }
+
+    pub fn boards(&self) -> &[Board] {
+        &self.grids
+    }
+
+    /// Calls numbers until a board wins, returning its index and the number that completed it.
+    pub fn find_winner(&mut self) -> Option<(usize, u32)> {
+        for value in &self.order {
+            for (i, grid) in self.grids.iter_mut().enumerate() {
+                if grid.mark(*value) {
+                    return Some((i, *value));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Calls numbers until every board has won, returning the index of the last one and the
+    /// number that completed it.
+    pub fn find_loser(&mut self) -> Option<(usize, u32)> {
+        let mut status: Vec<bool> = (0..self.grids.len()).map(|_| false).collect();
+
+        for value in &self.order {
+            for (i, grid) in self.grids.iter_mut().enumerate() {
+                if grid.mark(*value) {
+                    status[i] = true;
+
+                    if status.iter().all(|v| *v) {
+                        return Some((i, *value));
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    fn score(&self, (board, value): (usize, u32)) -> Result<u32> {
+        self.grids[board]
+            .score(value)
+            .ok_or(Error::NoSolution("the winning score is too large"))
+    }
+}
+
+impl BingoFile {
+    /// Draws the boards side by side, with marked numbers in white.
+    pub fn draw(&self) -> Image {
+        let width = self
+            .grids
+            .iter()
+            .map(|b| b.values.width() + 1)
+            .sum::<usize>()
+            - 1;
+        let height = self
+            .grids
+            .iter()
+            .map(|b| b.values.height())
+            .max()
+            .unwrap_or_default();
+
+        let mut canvas = Grid::filled(width, height, Rgb::BLACK);
+        let mut left = 0;
+
+        for board in &self.grids {
+            for (coord, value) in board.values.iter() {
+                canvas[Coord::new(left + coord.x, coord.y)] = match value.state {
+                    State::Marked => Rgb::WHITE,
+                    State::Unmarked => Rgb::grey(64),
+                };
+            }
+
+            left += board.values.width() + 1;
+        }
+
+        Image::from_grid(&canvas, 8, |colour| *colour)
+    }
+}
+
+impl Render for BingoFile {
+    /// One frame per number called, stopping once every board has won.
+    fn frames(&self) -> Vec<Image> {
+        let mut file = self.clone();
+        let mut frames = vec![file.draw()];
+        let mut won = vec![false; file.grids.len()];
+
+        for value in &self.order {
+            for (i, board) in file.grids.iter_mut().enumerate() {
+                won[i] |= board.mark(*value);
+            }
+
+            frames.push(file.draw());
+
+            if won.iter().all(|w| *w) {
+                break;
+            }
+        }
+
+        frames
+    }
+}
+
+impl Solution for BingoFile {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn from_input(input: &str) -> Result<Self> {
+        let bingo_file = parse::finish(input, Self::parse)?;
+
+        Ok(bingo_file)
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let mut file = self.clone();
+        let winner = file
+            .find_winner()
+            .ok_or(Error::NoSolution("no board ever wins"))?;
+
+        file.score(winner)
+    }
+
+    fn part2(&self) -> Result<u32> {
+        let mut file = self.clone();
+        let loser = file
+            .find_loser()
+            .ok_or(Error::NoSolution("not every board wins"))?;
+
+        file.score(loser)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let bingo_file = BingoFile::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(bingo_file.part1().unwrap(), 4512);
+        assert_eq!(bingo_file.part2().unwrap(), 1924);
+    }
+
+    #[test]
+    fn large_scores_are_reported() {
+        let bingo_file = BingoFile::from_input("2\n\n3000000000 2\n").unwrap();
+
+        assert!(matches!(bingo_file.part1(), Err(Error::NoSolution(_))));
+        assert!(matches!(bingo_file.part2(), Err(Error::NoSolution(_))));
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<BingoFile>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,340 @@
+use std::collections::HashSet;
+
+use nom::sequence::separated_pair;
+
+use aoc_common::grid::{Coord, Grid};
+use aoc_common::parse::{self, tag, IResult};
+use aoc_common::point::{BoundingBox, Point};
+use aoc_common::render::{Image, Render, Rgb};
+use aoc_common::{Error, Result, Solution};
+
+/// The most points the vent lines may cover between them before they're too large to map.
+const MAX_POINTS: u64 = 50_000_000;
+
+/// A line of hydrothermal vents, such as `0,9 -> 5,9`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Line {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl Line {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (input, (start, end)) = separated_pair(Point::parse, tag(" -> "), Point::parse)(input)?;
+
+        Ok((input, Self { start, end }))
+    }
+
+    pub fn is_horizontal_or_vertical(&self) -> bool {
+        self.start.x == self.end.x || self.start.y == self.end.y
+    }
+
+    /// How many points the line covers, counting both ends.
+    pub fn point_count(&self) -> u64 {
+        let dx = self.start.x.abs_diff(self.end.x);
+        let dy = self.start.y.abs_diff(self.end.y);
+
+        u64::from(dx.max(dy)) + 1
+    }
+
+    /// Every point the line covers, for horizontal, vertical and 45 degree lines, from the
+    /// leftmost end (or the top end, for vertical lines).
+    pub fn points(&self) -> Vec<Point> {
+        let (start, end) = (self.start.min(self.end), self.start.max(self.end));
+
+        // Stepping in i64 as the distance between the ends may not fit in an i32
+        let step = |from: i32, to: i32, i: i64| {
+            let value = i64::from(from) + i64::from((to - from).signum()) * i;
+            i32::try_from(value).expect("points between the ends fit in an i32")
+        };
+
+        (0..self.point_count() as i64)
+            .map(|i| Point::new(step(start.x, end.x, i), step(start.y, end.y, i)))
+            .collect()
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    lines: Vec<Line>,
+}
+
+impl Input {
+    pub fn new(lines: Vec<Line>) -> Self {
+        Self { lines }
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        let (input, lines) = parse::lines(Line::parse)(input)?;
+
+        Ok((input, Self { lines }))
+    }
+
+    pub fn lines(&self) -> &[Line] {
+        &self.lines
+    }
+
+    /// Fails if the lines picked by `filter` cover too many points to map.
+    fn check_size(&self, filter: impl Fn(&Line) -> bool) -> Result<()> {
+        let total: u64 = self
+            .lines
+            .iter()
+            .filter(|l| filter(l))
+            .map(Line::point_count)
+            .sum();
+
+        match total <= MAX_POINTS {
+            true => Ok(()),
+            false => Err(Error::NoSolution("the vent lines are too long to map")),
+        }
+    }
+
+    /// Counts the points where at least two horizontal or vertical lines overlap.
+    pub fn calculate_dangerous_point_count(&self) -> usize {
+        let mut covered_points = HashSet::new();
+        let mut dangerous_points = HashSet::new();
+
+        self.lines
+            .iter()
+            .copied()
+            .filter(Line::is_horizontal_or_vertical)
+            .for_each(|line| {
+                for point in line.points() {
+                    if !covered_points.insert(point) {
+                        dangerous_points.insert(point);
+                    }
+                }
+            });
+
+        dangerous_points.len()
+    }
+
+    /// Counts the points where at least two lines of any kind overlap.
+    pub fn calculate_dangerous_point_count_with_diagonals(&self) -> usize {
+        let mut covered_points = HashSet::new();
+        let mut dangerous_points = HashSet::new();
+
+        self.lines.iter().for_each(|line| {
+            for point in line.points() {
+                if !covered_points.insert(point) {
+                    dangerous_points.insert(point);
+                }
+            }
+        });
+
+        dangerous_points.len()
+    }
+}
+
+impl Render for Input {
+    /// Colours each point by how many vent lines cross it, from none in black to the most in red.
+    fn frames(&self) -> Vec<Image> {
+        let ends = self.lines.iter().flat_map(|line| [line.start, line.end]);
+
+        let Some(bounds) = BoundingBox::from_points(ends) else {
+            return Vec::new();
+        };
+
+        let side = |min: i32, max: i32| u64::from(min.abs_diff(max)) + 1;
+        let area =
+            side(bounds.min.x, bounds.max.x).saturating_mul(side(bounds.min.y, bounds.max.y));
+
+        if area > MAX_POINTS || self.check_size(|_| true).is_err() {
+            return Vec::new();
+        }
+
+        let mut overlaps = Grid::filled(bounds.width() as usize, bounds.height() as usize, 0u32);
+
+        for point in self.lines.iter().flat_map(Line::points) {
+            let offset = point - bounds.min;
+            overlaps[Coord::new(offset.x as usize, offset.y as usize)] += 1;
+        }
+
+        let most = overlaps.values().copied().max().unwrap_or_default();
+
+        let image = Image::from_grid(&overlaps, 1, |count| match count {
+            0 => Rgb::BLACK,
+            n => Rgb::grey(80).lerp(Rgb(255, 40, 40), f64::from(*n) / f64::from(most)),
+        });
+
+        vec![image]
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn from_input(input: &str) -> Result<Self> {
+        let input = parse::finish(input, Self::parse)?;
+
+        Ok(input)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        self.check_size(Line::is_horizontal_or_vertical)?;
+
+        Ok(self.calculate_dangerous_point_count())
+    }
+
+    fn part2(&self) -> Result<usize> {
+        self.check_size(|_| true)?;
+
+        Ok(self.calculate_dangerous_point_count_with_diagonals())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let input = Input::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(input.part1().unwrap(), 5);
+        assert_eq!(input.part2().unwrap(), 12);
+    }
+
+    #[test]
+    fn long_lines_are_reported() {
+        let line = Line::parse("-2000000000,0 -> 2000000000,0").unwrap().1;
+        let input = Input::new(vec![line]);
+
+        assert_eq!(line.point_count(), 4_000_000_001);
+        assert!(matches!(input.part1(), Err(Error::NoSolution(_))));
+        assert!(matches!(input.part2(), Err(Error::NoSolution(_))));
+        assert!(input.frames().is_empty());
+
+        let line = Line::parse("2147483647,5 -> 2147483645,7").unwrap().1;
+
+        assert_eq!(
+            line.points(),
+            [
+                Point::new(2147483645, 7),
+                Point::new(2147483646, 6),
+                Point::new(2147483647, 5)
+            ]
+        );
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<Input>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    /// Horizontal, vertical and 45 degree lines, the only kinds in the puzzle.
+    fn line() -> impl Strategy<Value = Line> {
+        let direction = (-1..=1, -1..=1).prop_filter("lines need a direction", |d| *d != (0, 0));
+
+        (-100..100, -100..100, direction, 0..50).prop_map(|(x, y, (dx, dy), length)| {
+            let start = Point::new(x, y);
+
+            Line {
+                start,
+                end: start + Point::new(dx, dy) * length,
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn points_cover_the_whole_line(line in line()) {
+            let points = line.points();
+            let dx = (line.end.x - line.start.x).abs();
+            let dy = (line.end.y - line.start.y).abs();
+
+            prop_assert_eq!(points.len() as i32, dx.max(dy) + 1);
+            prop_assert!(points.contains(&line.start));
+            prop_assert!(points.contains(&line.end));
+        }
+    }
+
+    #[test]
+    fn lines_are_as_expected() {
+        let line = Line {
+            start: Point { x: 5, y: 5 },
+            end: Point { x: 5, y: 8 },
+        };
+        let expected = vec![
+            Point { x: 5, y: 5 },
+            Point { x: 5, y: 6 },
+            Point { x: 5, y: 7 },
+            Point { x: 5, y: 8 },
+        ];
+
+        assert_eq!(line.points(), expected);
+
+        let line = Line {
+            start: Point { x: 5, y: 8 },
+            end: Point { x: 5, y: 5 },
+        };
+        let expected = vec![
+            Point { x: 5, y: 5 },
+            Point { x: 5, y: 6 },
+            Point { x: 5, y: 7 },
+            Point { x: 5, y: 8 },
+        ];
+
+        assert_eq!(line.points(), expected);
+
+        let line = Line {
+            start: Point { x: 5, y: 5 },
+            end: Point { x: 8, y: 5 },
+        };
+        let expected = vec![
+            Point { x: 5, y: 5 },
+            Point { x: 6, y: 5 },
+            Point { x: 7, y: 5 },
+            Point { x: 8, y: 5 },
+        ];
+
+        assert_eq!(line.points(), expected);
+
+        let line = Line {
+            start: Point { x: 8, y: 5 },
+            end: Point { x: 5, y: 5 },
+        };
+        let expected = vec![
+            Point { x: 5, y: 5 },
+            Point { x: 6, y: 5 },
+            Point { x: 7, y: 5 },
+            Point { x: 8, y: 5 },
+        ];
+
+        assert_eq!(line.points(), expected);
+    }
+
+    #[test]
+    fn diagonal_lines() {
+        let line = Line {
+            start: Point { x: 8, y: 8 },
+            end: Point { x: 5, y: 5 },
+        };
+        let expected = vec![
+            Point { x: 5, y: 5 },
+            Point { x: 6, y: 6 },
+            Point { x: 7, y: 7 },
+            Point { x: 8, y: 8 },
+        ];
+
+        assert_eq!(line.points(), expected);
+
+        let line = Line {
+            start: Point { x: 5, y: 8 },
+            end: Point { x: 8, y: 5 },
+        };
+        let expected = vec![
+            Point { x: 5, y: 8 },
+            Point { x: 6, y: 7 },
+            Point { x: 7, y: 6 },
+            Point { x: 8, y: 5 },
+        ];
+
+        assert_eq!(line.points(), expected);
+    }
+}
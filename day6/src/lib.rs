@@ -0,0 +1,125 @@
+use nom::{
+    combinator::{map, verify},
+    error::context,
+};
+
+use aoc_common::parse::{self, IResult};
+use aoc_common::{Result, Solution};
+
+/// The internal timer of every lanternfish in the school.
+#[derive(Clone, Debug)]
+pub struct State {
+    lanternfish: Vec<u32>,
+}
+
+impl State {
+    pub fn new(lanternfish: Vec<u32>) -> Self {
+        Self { lanternfish }
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            parse::comma_separated(context(
+                "a timer from 0 to 8",
+                verify(parse::u32, |timer| *timer <= 8),
+            )),
+            State::new,
+        )(input)
+    }
+
+    pub fn lanternfish(&self) -> &[u32] {
+        &self.lanternfish
+    }
+
+    /// Simulates a single day, fish by fish.
+    pub fn tick(&self) -> Self {
+        let mut next = Vec::new();
+
+        for fish in &self.lanternfish {
+            if *fish == 0 {
+                next.extend_from_slice(&[6, 8]);
+            } else {
+                next.push(fish - 1);
+            }
+        }
+
+        Self::new(next)
+    }
+
+    /// Counts the fish after `days` without tracking each one, by grouping them on their timers.
+    pub fn population(&self, days: usize) -> u64 {
+        let longest = self.lanternfish.iter().max().map_or(0, |t| *t as usize);
+        let mut timers = vec![0u64; longest.max(8) + 1];
+
+        for fish in &self.lanternfish {
+            timers[*fish as usize] += 1;
+        }
+
+        for _ in 0..days {
+            // Fish at 0 reset to 6 and each spawn a new fish at 8
+            let spawning = timers.remove(0);
+            timers.push(0);
+
+            timers[6] += spawning;
+            timers[8] += spawning;
+        }
+
+        timers.iter().sum()
+    }
+}
+
+impl Solution for State {
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn from_input(input: &str) -> Result<Self> {
+        let state = parse::finish(input, Self::parse)?;
+
+        Ok(state)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        let ticked = (0..80).fold(self.clone(), |s, _| s.tick());
+
+        Ok(ticked.lanternfish.len())
+    }
+
+    fn part2(&self) -> Result<u64> {
+        Ok(self.population(256))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let state = State::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(state.part1().unwrap(), 5934);
+        assert_eq!(state.part2().unwrap(), 26984457539);
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<State>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    proptest! {
+        #[test]
+        fn population_matches_simulation(
+            lanternfish in prop::collection::vec(0u32..=8, 0..20),
+            days in 0usize..40,
+        ) {
+            let state = State::new(lanternfish);
+            let simulated = (0..days).fold(state.clone(), |s, _| s.tick());
+
+            prop_assert_eq!(state.population(days), simulated.lanternfish.len() as u64);
+        }
+    }
+}
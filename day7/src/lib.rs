@@ -0,0 +1,136 @@
+use aoc_common::parse::{self, IResult};
+use aoc_common::{Error, Result, Solution};
+
+/// Fuel needed for a crab at `position` to move to `i` when each step costs one more than the
+/// last, or `None` if it doesn't fit in an `i32`.
+pub fn calculate_fuel_cost_for_position(i: i32, position: i32) -> Option<i32> {
+    let distance = i.checked_sub(position)?.checked_abs()?;
+    Some(distance.checked_mul(distance.checked_add(1)?)? / 2)
+}
+
+pub fn compute_fuel_cost_for_all_positions(i: i32, positions: &[i32]) -> Option<i32> {
+    positions.iter().try_fold(0i32, |total, p| {
+        total.checked_add(calculate_fuel_cost_for_position(i, *p)?)
+    })
+}
+
+const NO_CRABS: Error = Error::NoSolution("there are no crabs to align");
+const TOO_MUCH_FUEL: Error = Error::NoSolution("the fuel needed is too large");
+
+/// Horizontal positions of the crab submarines, kept sorted.
+#[derive(Debug)]
+pub struct Crabs {
+    positions: Vec<i32>,
+}
+
+impl Crabs {
+    pub fn new(mut positions: Vec<i32>) -> Self {
+        positions.sort();
+        Self { positions }
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        nom::combinator::map(parse::comma_separated(parse::i32), Self::new)(input)
+    }
+
+    pub fn positions(&self) -> &[i32] {
+        &self.positions
+    }
+
+    /// Fuel needed to align every crab when each step costs 1.
+    pub fn calculate_minimal_fuel(&self) -> Result<i32> {
+        // With an even number of crabs, anywhere between the middle two costs the same
+        let median = *self
+            .positions
+            .get(self.positions.len().saturating_sub(1) / 2)
+            .ok_or(NO_CRABS)?;
+
+        self.positions
+            .iter()
+            .try_fold(0i32, |total, p| {
+                total.checked_add(p.checked_sub(median)?.checked_abs()?)
+            })
+            .ok_or(TOO_MUCH_FUEL)
+    }
+
+    /// Fuel needed to align every crab when each step costs one more than the last.
+    pub fn calculate_complex_minimal_fuel(&self) -> Result<i32> {
+        let (first, last) = self
+            .positions
+            .first()
+            .zip(self.positions.last())
+            .ok_or(NO_CRABS)?;
+
+        // For each position, compute the fuel cost, skipping any too large to count as they
+        // can't be the cheapest
+        (*first..=*last)
+            .filter_map(|i| compute_fuel_cost_for_all_positions(i, &self.positions))
+            .min()
+            .ok_or(TOO_MUCH_FUEL)
+    }
+}
+
+impl Solution for Crabs {
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn from_input(input: &str) -> Result<Self> {
+        let crabs = parse::finish(input, Self::parse)?;
+
+        Ok(crabs)
+    }
+
+    fn part1(&self) -> Result<i32> {
+        self.calculate_minimal_fuel()
+    }
+
+    fn part2(&self) -> Result<i32> {
+        self.calculate_complex_minimal_fuel()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let crabs = Crabs::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(crabs.part1().unwrap(), 37);
+        assert_eq!(crabs.part2().unwrap(), 168);
+    }
+
+    #[test]
+    fn large_fuel_costs_are_reported() {
+        let crabs = Crabs::from_input("0,100000").unwrap();
+
+        assert_eq!(crabs.part1().unwrap(), 100000);
+        assert!(matches!(crabs.part2(), Err(Error::NoSolution(_))));
+
+        let crabs = Crabs::new(vec![i32::MIN, i32::MAX]);
+
+        assert!(matches!(crabs.part1(), Err(Error::NoSolution(_))));
+        assert!(matches!(
+            Crabs::new(Vec::new()).part2(),
+            Err(Error::NoSolution("there are no crabs to align"))
+        ));
+    }
+
+    #[test]
+    fn positions_too_large_are_reported() {
+        let error = Crabs::from_input("0,3000000000\n").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 3: expected a number that fits in an i32, found `3000000000`"
+        );
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<Crabs>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,100 @@
+use nom::{
+    character::complete::{alpha1, space0},
+    combinator::map,
+    multi::many_m_n,
+    sequence::{delimited, separated_pair},
+};
+
+use aoc_common::parse::{self, tag, IResult};
+use aoc_common::{Error, Result, Solution, Unsolved};
+
+/// Whether a pattern can only be a 1, 4, 7 or 8 based on how many segments it lights.
+pub fn is_unique(value: &str) -> bool {
+    matches!(value.len(), 2 | 3 | 4 | 7)
+}
+
+/// The ten unique signal patterns seen on a display, and the four digits of its output.
+#[derive(Debug)]
+pub struct Entry {
+    pub signals: Vec<String>,
+    pub outputs: Vec<String>,
+}
+
+fn pattern(input: &str) -> IResult<&str, String> {
+    map(delimited(space0, alpha1, space0), String::from)(input)
+}
+
+impl Entry {
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(
+            separated_pair(many_m_n(10, 10, pattern), tag("|"), many_m_n(4, 4, pattern)),
+            |(signals, outputs)| Self { signals, outputs },
+        )(input)
+    }
+}
+
+#[derive(Debug)]
+pub struct Input {
+    entries: Vec<Entry>,
+}
+
+impl Input {
+    pub fn new(entries: Vec<Entry>) -> Self {
+        Self { entries }
+    }
+
+    pub fn parse(input: &str) -> IResult<&str, Self> {
+        map(parse::lines(Entry::parse), |entries| Self { entries })(input)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Counts the output digits that must be a 1, 4, 7 or 8.
+    pub fn unique_digit_outputs(&self) -> usize {
+        self.entries
+            .iter()
+            .flat_map(|e| e.outputs.iter())
+            .filter(|output| is_unique(output))
+            .count()
+    }
+}
+
+impl Solution for Input {
+    type Part1 = usize;
+    type Part2 = Unsolved;
+
+    fn from_input(input: &str) -> Result<Self> {
+        let input = parse::finish(input, Self::parse)?;
+
+        Ok(input)
+    }
+
+    fn part1(&self) -> Result<usize> {
+        Ok(self.unique_digit_outputs())
+    }
+
+    fn part2(&self) -> Result<Unsolved> {
+        Err(Error::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let input = Input::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(input.part1().unwrap(), 26);
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<Input>(env!("CARGO_MANIFEST_DIR"));
+    }
+}
@@ -0,0 +1,114 @@
+use aoc_common::grid::{Coord, Grid};
+use aoc_common::render::{Image, Render, Rgb};
+use aoc_common::{parse, search};
+use aoc_common::{Result, Solution};
+
+/// Heights of the sea floor, from 0 to 9.
+#[derive(Debug)]
+pub struct DepthMap {
+    values: Grid<u32>,
+}
+
+impl DepthMap {
+    pub fn new(values: Grid<u32>) -> Self {
+        Self { values }
+    }
+
+    pub fn values(&self) -> &Grid<u32> {
+        &self.values
+    }
+
+    /// Whether every location next to `coord` is higher than it.
+    pub fn no_point_lower(&self, coord: Coord) -> bool {
+        let centre = self.values[coord];
+
+        self.values
+            .neighbours(coord)
+            .all(|neighbour| self.values[neighbour] > centre)
+    }
+
+    pub fn lowest_points(&self) -> Vec<Coord> {
+        self.values
+            .coords()
+            .filter(|coord| self.no_point_lower(*coord))
+            .collect()
+    }
+
+    /// Size of the basin flowing down to `low_point`, which is bounded by height 9 locations.
+    pub fn basin_size(&self, low_point: Coord) -> usize {
+        search::reachable(low_point, |coord| {
+            self.values
+                .neighbours(*coord)
+                .filter(|neighbour| self.values[*neighbour] != 9)
+                .collect::<Vec<_>>()
+        })
+        .len()
+    }
+}
+
+impl Render for DepthMap {
+    /// Shades the heights from dark to light, with the low points in red.
+    fn frames(&self) -> Vec<Image> {
+        let low_points = self.lowest_points();
+
+        let mut shaded = self
+            .values
+            .map(|height| Rgb::BLACK.lerp(Rgb::WHITE, f64::from(*height) / 9.0));
+
+        for point in low_points {
+            shaded[point] = Rgb(220, 40, 40);
+        }
+
+        vec![Image::from_grid(&shaded, 4, |colour| *colour)]
+    }
+}
+
+impl Solution for DepthMap {
+    type Part1 = u32;
+    type Part2 = usize;
+
+    fn from_input(input: &str) -> Result<Self> {
+        let values = parse::finish(input, parse::digit_grid)?;
+
+        Ok(Self { values })
+    }
+
+    fn part1(&self) -> Result<u32> {
+        let lowest_points = self.lowest_points();
+        let answer = lowest_points.iter().map(|p| self.values[*p] + 1).sum();
+
+        Ok(answer)
+    }
+
+    fn part2(&self) -> Result<usize> {
+        let mut sizes: Vec<_> = self
+            .lowest_points()
+            .into_iter()
+            .map(|p| self.basin_size(p))
+            .collect();
+
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+        Ok(sizes.iter().take(3).product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../example.txt");
+
+    #[test]
+    fn example_answers() {
+        let depth_map = DepthMap::from_input(EXAMPLE).unwrap();
+
+        assert_eq!(depth_map.part1().unwrap(), 15);
+        assert_eq!(depth_map.part2().unwrap(), 1134);
+    }
+
+    #[test]
+    fn local_answers() {
+        aoc_common::testing::check_local_answers::<DepthMap>(env!("CARGO_MANIFEST_DIR"));
+    }
+}